@@ -0,0 +1,38 @@
+use advent_of_code_2025::input::{
+    default_input_path, example_path, normalize, read_example, read_input_file,
+};
+use advent_of_code_2025::registry::{Solver, SOLVERS};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn bench_solver(c: &mut Criterion, solver: &Solver, name: &str, input: &str, sample_size: usize) {
+    let mut group = c.benchmark_group(format!("day{:02}/{name}", solver.day));
+    group.sample_size(sample_size);
+
+    group.bench_function("parse", |b| b.iter(|| (solver.parse)(black_box(input))));
+
+    // Not every example fits both parts; those parts are timed up to their error.
+    let parsed = (solver.parse)(input).expect("inputs parse");
+    group.bench_function("part1", |b| b.iter(|| parsed.part1()));
+    group.bench_function("part2", |b| b.iter(|| parsed.part2()));
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    for solver in SOLVERS {
+        let examples = (1..).take_while(|&index| example_path(solver.day, index).exists());
+        for index in examples {
+            let example = read_example(solver.day, index).expect("examples are checked in");
+            bench_solver(c, solver, &format!("example{index}"), &example, 100);
+        }
+
+        // Real inputs are benchmarked only if they have been fetched into `inputs/`.
+        if let Ok(input) = read_input_file(&default_input_path(solver.day)) {
+            let input = normalize(&input);
+            bench_solver(c, solver, "real", &input, 10);
+        }
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+#![no_main]
+
+use advent_of_code_2025::day02::Day02;
+use advent_of_code_2025::input::normalize;
+use advent_of_code_2025::Solution;
+use libfuzzer_sys::fuzz_target;
+
+/// Inputs longer than this are skipped; the solvers only promise to finish
+/// quickly on inputs around the size of a real puzzle input.
+const MAX_INPUT_LEN: usize = 4096;
+
+// Arbitrary input must give answers or errors, never panics or hangs. Bytes
+// go through the same UTF-8 decoding and normalisation as the runner's input.
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT_LEN {
+        return;
+    }
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let input = normalize(input);
+
+    let day = Day02::default();
+    if let Ok(parsed) = Day02::parse(&input) {
+        let _ = day.part1(&parsed);
+        let _ = day.part2(&parsed);
+    }
+});
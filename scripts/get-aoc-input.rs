@@ -5,7 +5,7 @@
 edition = "2021"
 
 [dependencies]
-clap = { version = "4.2", features = ["derive"] }
+clap = { version = "4.2", features = ["derive", "env"] }
 nom = "7.1.3"
 reqwest = { version = "0.11.22", features=["blocking"] }
 ---
@@ -16,9 +16,9 @@ use nom::{
     sequence::preceded, IResult,
 };
 use reqwest::{blocking::Client, header::COOKIE};
-use std::fs::File;
-use std::io::Write;
-use std::path::PathBuf;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[derive(Parser, Debug)]
 #[clap(version)]
@@ -33,15 +33,189 @@ struct Args {
     /// doing any shenanigans
     #[clap(long)]
     current_working_directory: PathBuf,
+    /// fetch the input again, even if it is
+    /// already present or cached
+    #[clap(long)]
+    force: bool,
+    /// where fetched inputs are kept, keyed by
+    /// year and day; defaults to `~/.cache/aoc`
+    #[clap(long)]
+    cache_dir: Option<PathBuf>,
+    /// where the example blocks of the puzzle
+    /// text are written as `day-01-<k>.txt`;
+    /// examples are skipped if not given
+    #[clap(long)]
+    examples_directory: Option<PathBuf>,
+    /// the event year to fetch the input of
+    #[clap(
+        long,
+        env = "AOC_YEAR",
+        default_value_t = 2025
+    )]
+    year: u32,
+    /// the site to fetch from, e.g. a local
+    /// stand-in server when testing
+    #[clap(
+        long,
+        env = "AOC_BASE_URL",
+        default_value = "https://adventofcode.com"
+    )]
+    base_url: String,
+    /// the session cookie of the logged in user
+    #[clap(
+        long,
+        env = "SESSION",
+        hide_env_values = true
+    )]
+    session: Option<String>,
 }
 
 fn parse_day(input: &str) -> IResult<&str, u32> {
     preceded(tag("day-"), complete::u32)(input)
 }
 
-fn main() -> Result<(), reqwest::Error> {
-    let session = std::env::var("SESSION")
-        .expect("should have a session token set");
+fn default_cache_dir() -> PathBuf {
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| {
+                PathBuf::from(home).join(".cache")
+            })
+        })
+        .unwrap_or_else(|| PathBuf::from(".cache"))
+        .join("aoc")
+}
+
+fn is_non_empty(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|meta| meta.len() > 0)
+}
+
+/// When the session cookie has expired the site
+/// answers with an error page or a "please log
+/// in" message instead of the puzzle input.
+fn looks_like_puzzle_input(body: &str) -> bool {
+    let body = body.trim_start();
+    !body.is_empty()
+        && !body.starts_with('<')
+        && !body.contains("Please log in")
+}
+
+/// Pulls the `<pre><code>` blocks out of the
+/// puzzle page, with markup such as `<em>`
+/// removed and entities decoded.
+fn extract_examples(page: &str) -> Vec<String> {
+    page.split("<pre><code>")
+        .skip(1)
+        .filter_map(|block| {
+            let (code, _) =
+                block.split_once("</code>")?;
+            Some(unescape_html(&strip_tags(code)))
+        })
+        .collect()
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .split_once('>')
+            .map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    text
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn day_url(args: &Args, day: u32) -> String {
+    format!(
+        "{}/{}/day/{day}",
+        args.base_url.trim_end_matches('/'),
+        args.year
+    )
+}
+
+fn fetch(
+    args: &Args,
+    day: u32,
+) -> Result<String, Box<dyn Error>> {
+    let Some(session) = &args.session else {
+        return Err(
+            "should have a session token set".into()
+        );
+    };
+
+    let url = format!("{}/input", day_url(args, day));
+    println!("sending to `{}`", url);
+
+    let client = Client::new();
+    let response = client
+        .get(url)
+        .header(COOKIE, format!("session={session}"))
+        .send()?;
+    let status = response.status();
+    let input_data = response.text()?;
+
+    if !status.is_success()
+        || !looks_like_puzzle_input(&input_data)
+    {
+        return Err(format!(
+            "got `{status}` without a puzzle input, \
+             has the SESSION cookie expired?"
+        )
+        .into());
+    }
+    Ok(input_data)
+}
+
+/// The puzzle page is public, but the session
+/// is sent along when set so that the examples
+/// of an unlocked part 2 are included.
+fn fetch_page(
+    args: &Args,
+    day: u32,
+) -> Result<String, Box<dyn Error>> {
+    let url = day_url(args, day);
+    println!("sending to `{}`", url);
+
+    let mut request = Client::new().get(url);
+    if let Some(session) = &args.session {
+        request = request.header(
+            COOKIE,
+            format!("session={session}"),
+        );
+    }
+    let response = request.send()?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!(
+            "got `{status}` for the puzzle page"
+        )
+        .into());
+    }
+    Ok(response.text()?)
+}
+
+fn write_file(
+    path: &Path,
+    content: &str,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
     let Ok((_, day)) = parse_day(&args.day) else {
         let mut cmd = Args::command();
@@ -55,32 +229,267 @@ fn main() -> Result<(), reqwest::Error> {
         .exit()
     };
 
-    let url = format!(
-        "https://adventofcode.com/2025/day/{day}/input"
-    );
-    println!("sending to `{}`", url);
+    get_input(&args, day)?;
+    if let Some(examples_directory) =
+        &args.examples_directory
+    {
+        get_examples(&args, day, examples_directory)?;
+    }
+    Ok(())
+}
 
-    let client = Client::new();
-    let input_data = client
-        .get(url)
-        .header(COOKIE, format!("session={session}"))
-        .send()?
-        .text()?;
-
-    for filename in [
-        format!("{}.txt", &args.day),
-    ] {
-        let file_path = args
-            .current_working_directory
-            .join(filename);
-        let mut file = File::create(&file_path)
-            .expect("should be able to create a file");
-
-        file.write_all(input_data.as_bytes()).expect(
-            "should be able to write to input file",
+fn get_input(
+    args: &Args,
+    day: u32,
+) -> Result<(), Box<dyn Error>> {
+    let filename = format!("{}.txt", &args.day);
+    let file_path =
+        args.current_working_directory.join(&filename);
+    let cache_path = args
+        .cache_dir
+        .clone()
+        .unwrap_or_else(default_cache_dir)
+        .join(args.year.to_string())
+        .join(&filename);
+
+    if !args.force && is_non_empty(&file_path) {
+        println!(
+            "{} already exists, pass --force to fetch it again",
+            file_path.display()
         );
-        println!("wrote {}", file_path.display());
+        return Ok(());
     }
 
+    let cached =
+        fs::read_to_string(&cache_path).ok().filter(
+            |cached| !args.force && !cached.is_empty(),
+        );
+
+    let input_data = match cached {
+        Some(cached) => {
+            println!(
+                "using cached {}",
+                cache_path.display()
+            );
+            cached
+        }
+        None => {
+            let input_data = fetch(args, day)?;
+            write_file(&cache_path, &input_data)?;
+            input_data
+        }
+    };
+
+    write_file(&file_path, &input_data)?;
+    println!("wrote {}", file_path.display());
+
     Ok(())
 }
+
+fn get_examples(
+    args: &Args,
+    day: u32,
+    examples_directory: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let example_path = |index: usize| {
+        examples_directory
+            .join(format!("{}-{index}.txt", &args.day))
+    };
+
+    if !args.force && is_non_empty(&example_path(1)) {
+        println!(
+            "{} already exists, pass --force to fetch the examples again",
+            example_path(1).display()
+        );
+        return Ok(());
+    }
+
+    let examples =
+        extract_examples(&fetch_page(args, day)?);
+    if examples.is_empty() {
+        println!(
+            "no examples found on the puzzle page"
+        );
+    }
+    for (index, example) in examples.iter().enumerate()
+    {
+        let path = example_path(index + 1);
+        write_file(&path, example)?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Answers a single request with `status` and
+    /// `body`, and returns the raw request head.
+    fn stub_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, JoinHandle<String>) {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!(
+            "http://{}",
+            listener.local_addr().unwrap()
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) =
+                listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n =
+                    stream.read(&mut buffer).unwrap();
+                request
+                    .extend_from_slice(&buffer[..n]);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\n\
+                 Content-Length: {}\r\n\
+                 Connection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (base_url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "get-aoc-input-{}-{name}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn args(base_url: &str, dir: &Path) -> Args {
+        Args::try_parse_from([
+            "get-aoc-input",
+            "--day",
+            "day-07",
+            "--current-working-directory",
+            dir.join("inputs").to_str().unwrap(),
+            "--cache-dir",
+            dir.join("cache").to_str().unwrap(),
+            "--year",
+            "2024",
+            "--base-url",
+            base_url,
+            "--session",
+            "c00k1e",
+            "--force",
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        let page = "<p>For example:</p>\n\
+            <pre><code>L68\nL30\n</code></pre>\n\
+            <p>Then <code>R48</code>:</p>\n\
+            <pre><code>a &lt;-&gt; <em>b</em>\n</code></pre>";
+
+        assert_eq!(
+            vec!["L68\nL30\n", "a <-> b\n"],
+            extract_examples(page)
+        );
+    }
+
+    #[test]
+    fn test_writes_examples() {
+        let (base_url, server) = stub_server(
+            "200 OK",
+            "<pre><code>L68\n</code></pre>\
+             <pre><code>R48\n</code></pre>",
+        );
+        let dir = temp_dir("examples");
+
+        get_examples(
+            &args(&base_url, &dir),
+            7,
+            &dir.join("examples"),
+        )
+        .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(
+            request.starts_with("GET /2024/day/7 ")
+        );
+        assert_eq!(
+            "L68\n",
+            fs::read_to_string(
+                dir.join("examples/day-07-1.txt")
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            "R48\n",
+            fs::read_to_string(
+                dir.join("examples/day-07-2.txt")
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fetches_and_writes_input() {
+        let (base_url, server) =
+            stub_server("200 OK", "..S..\n.^.^.\n");
+        let dir = temp_dir("fetch");
+
+        get_input(&args(&base_url, &dir), 7).unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with(
+            "GET /2024/day/7/input HTTP/1.1"
+        ));
+        assert!(request
+            .to_lowercase()
+            .contains("cookie: session=c00k1e\r\n"));
+        for path in [
+            dir.join("inputs/day-07.txt"),
+            dir.join("cache/2024/day-07.txt"),
+        ] {
+            assert_eq!(
+                "..S..\n.^.^.\n",
+                fs::read_to_string(path).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_keeps_input_on_expired_session() {
+        let (base_url, server) = stub_server(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  \
+             Please log in to get your puzzle input.",
+        );
+        let dir = temp_dir("expired");
+        let input_path = dir.join("inputs/day-07.txt");
+        write_file(&input_path, "..S..\n").unwrap();
+
+        assert!(get_input(&args(&base_url, &dir), 7)
+            .is_err());
+
+        server.join().unwrap();
+        assert_eq!(
+            "..S..\n",
+            fs::read_to_string(input_path).unwrap()
+        );
+        assert!(!dir
+            .join("cache/2024/day-07.txt")
+            .exists());
+    }
+}
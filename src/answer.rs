@@ -0,0 +1,151 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// The answer to one part of a puzzle. Integers keep their type, and compare
+/// by value regardless of their width, so that `Answer::from(3_usize)` equals
+/// `Answer::from(3_u64)`.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    I32(i32),
+    I64(i64),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    Text(String),
+    /// the part has no solution yet
+    Unsolved,
+}
+
+impl Answer {
+    fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Answer::I32(n) => Some(n.into()),
+            Answer::I64(n) => Some(n.into()),
+            Answer::U32(n) => Some(n.into()),
+            Answer::U64(n) => Some(n.into()),
+            Answer::U128(n) => n.try_into().ok(),
+            Answer::Usize(n) => n.try_into().ok(),
+            Answer::Text(_) | Answer::Unsolved => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (Answer::U128(a), Answer::U128(b)) => a == b,
+            _ => self.as_i128().is_some() && self.as_i128() == other.as_i128(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I32(n) => n.fmt(f),
+            Answer::I64(n) => n.fmt(f),
+            Answer::U32(n) => n.fmt(f),
+            Answer::U64(n) => n.fmt(f),
+            Answer::U128(n) => n.fmt(f),
+            Answer::Usize(n) => n.fmt(f),
+            Answer::Text(text) => text.fmt(f),
+            Answer::Unsolved => f.write_str("unsolved"),
+        }
+    }
+}
+
+/// Integers are written as numbers and an unsolved part as `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Answer::I32(n) => serializer.serialize_i32(n),
+            Answer::I64(n) => serializer.serialize_i64(n),
+            Answer::U32(n) => serializer.serialize_u32(n),
+            Answer::U64(n) => serializer.serialize_u64(n),
+            Answer::U128(n) => serializer.serialize_u128(n),
+            Answer::Usize(n) => serializer.serialize_u64(n as u64),
+            Answer::Text(ref text) => serializer.serialize_str(text),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($variant:ident($int:ty)),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::$variant(n)
+                }
+            }
+
+            // Building an integer answer is free, unlike the owned values
+            // `cmp_owned` is meant to catch.
+            #[allow(clippy::cmp_owned)]
+            impl PartialEq<$int> for Answer {
+                fn eq(&self, other: &$int) -> bool {
+                    *self == Answer::from(*other)
+                }
+            }
+
+            #[allow(clippy::cmp_owned)]
+            impl PartialEq<Answer> for $int {
+                fn eq(&self, other: &Answer) -> bool {
+                    Answer::from(*self) == *other
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(
+    I32(i32),
+    I64(i64),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize)
+);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compares_integers_by_value() {
+        assert_eq!(Answer::from(3_usize), Answer::from(3_u64));
+        assert_eq!(3121910778619_u64, Answer::from(3121910778619_i64));
+        assert_ne!(Answer::from(-1), Answer::from(u128::MAX));
+        assert_ne!(Answer::from("3"), Answer::from(3));
+        assert_ne!(Answer::Unsolved, Answer::from(0));
+    }
+
+    #[test]
+    fn test_serialises_integers_as_numbers() {
+        let answers = [
+            Answer::from(u128::MAX),
+            Answer::from(-40_i64),
+            Answer::from("abc"),
+            Answer::Unsolved,
+        ];
+        assert_eq!(
+            "[340282366920938463463374607431768211455,-40,\"abc\",null]",
+            serde_json::to_string(&answers).unwrap()
+        );
+    }
+}
@@ -0,0 +1,326 @@
+mod new;
+mod output;
+mod submit;
+mod trace;
+mod verify;
+
+use crate::new::new_day;
+use crate::output::{input_hash, print_header, print_record, Format, Record};
+use crate::submit::{submit, Endpoint};
+use crate::trace::trace;
+use crate::verify::verify;
+use advent_of_code_2025::input::read_input;
+use advent_of_code_2025::registry::{self, Solver, SOLVERS};
+use advent_of_code_2025::AocError;
+use advent_of_code_2025::AocError::ValueError;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Parser, Debug)]
+#[clap(version)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// run the solvers of a single day, or of `all` days
+    Run {
+        /// day number such as `5`, or `all`
+        day: DaySelection,
+        /// only run the given part
+        #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// puzzle input file, or `-` to read from stdin;
+        /// defaults to `inputs/day-NN.txt`
+        #[clap(short, long)]
+        input: Option<PathBuf>,
+        /// time every phase over several runs and print a table
+        #[clap(long)]
+        bench: bool,
+        /// number of runs per phase in `--bench` mode
+        #[clap(long, default_value_t = 10, requires = "bench")]
+        repeat: usize,
+        /// output format of the answers
+        #[clap(long, value_enum, default_value_t = Format::Text, conflicts_with = "bench")]
+        format: Format,
+    },
+    /// check the answers of the solvers on their real input against the
+    /// accepted answers
+    Verify {
+        /// day number such as `5`, or `all`
+        #[clap(default_value = "all")]
+        day: DaySelection,
+        /// file with the accepted answers
+        #[clap(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// record the current answers as the accepted ones
+        #[clap(long)]
+        update: bool,
+    },
+    /// create the module of a new day from a template and register it
+    New {
+        /// day number such as `5`
+        #[clap(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// let the tests load the examples fetched into `examples/`
+        #[clap(long)]
+        examples: bool,
+    },
+    /// show turn by turn how the input moves the day 1 dial
+    Trace {
+        /// day number; only day 1 can be traced
+        #[clap(value_parser = clap::value_parser!(u8).range(1..=1))]
+        day: u8,
+        /// puzzle input file, or `-` to read from stdin;
+        /// defaults to `inputs/day-NN.txt`
+        #[clap(short, long)]
+        input: Option<PathBuf>,
+        /// output format: an aligned table, JSON lines or CSV
+        #[clap(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// submit the answer of a part on the real input, unless an earlier
+    /// submission already tells how it would be judged
+    Submit {
+        /// day number such as `5`
+        day: u8,
+        /// part to submit the answer of
+        #[clap(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// puzzle input file, or `-` to read from stdin;
+        /// defaults to `inputs/day-NN.txt`
+        #[clap(short, long)]
+        input: Option<PathBuf>,
+        /// file in which every submission is recorded
+        #[clap(long, default_value = "submissions.toml")]
+        history: PathBuf,
+        /// the event year the puzzle belongs to
+        #[clap(long, env = "AOC_YEAR", default_value_t = 2025)]
+        year: u32,
+        /// the site to submit to, e.g. a local stand-in server when testing
+        #[clap(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+        base_url: String,
+        /// the session cookie of the logged in user
+        #[clap(long, env = "SESSION", hide_env_values = true)]
+        session: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(DaySelection::All),
+            _ => s
+                .parse()
+                .map(DaySelection::Day)
+                .map_err(|_| format!("day `{s}` must be a number or `all`")),
+        }
+    }
+}
+
+fn select_solvers(day: DaySelection) -> Result<Vec<&'static Solver>, AocError> {
+    match day {
+        DaySelection::All => Ok(SOLVERS.iter().collect()),
+        DaySelection::Day(day) => {
+            let solver =
+                registry::find(day).ok_or(ValueError(format!("No solver for day {day}")))?;
+            Ok(vec![solver])
+        }
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<(T, Duration), AocError> {
+    let start = Instant::now();
+    let result = f()?;
+    Ok((result, start.elapsed()))
+}
+
+/// Parses the input of `solver` once and runs the selected parts on it,
+/// printing a record for each of them. Returns the number of parts that failed.
+fn run(
+    solver: &Solver,
+    part: Option<u8>,
+    input: Result<String, AocError>,
+    format: Format,
+) -> usize {
+    let input = input.map_err(|e| e.to_string());
+    let hash = input.as_deref().ok().map(input_hash);
+    let parsed = input
+        .as_deref()
+        .map_err(Clone::clone)
+        .and_then(|input| timed(|| (solver.parse)(input)).map_err(|e| e.to_string()));
+    let parse_duration = parsed.as_ref().ok().map(|&(_, duration)| duration);
+
+    if format == Format::Text {
+        println!("Day {:02}", solver.day);
+        match &parsed {
+            Ok((_, duration)) => println!("Parse: {duration:.2?}"),
+            Err(e) if input.is_ok() => println!("Parse: {e}"),
+            Err(_) => {}
+        }
+    }
+
+    let mut failures = 0;
+    for part_number in [1, 2] {
+        if part.is_some_and(|p| p != part_number) {
+            continue;
+        }
+
+        let result = parsed
+            .as_ref()
+            .map_err(Clone::clone)
+            .and_then(|(parsed, _)| timed(|| parsed.part(part_number)).map_err(|e| e.to_string()));
+        let record = Record::new(
+            solver.day,
+            part_number,
+            hash.as_deref(),
+            parse_duration,
+            result,
+        );
+        if record.error.is_some() {
+            failures += 1;
+        }
+        print_record(format, &record);
+    }
+    failures
+}
+
+/// Runs `f` `repeat` times and returns the min, median and max duration.
+fn measure<T>(
+    repeat: usize,
+    f: impl Fn() -> Result<T, AocError>,
+) -> Result<[Duration; 3], AocError> {
+    let mut durations = (0..repeat.max(1))
+        .map(|_| timed(&f).map(|(_, duration)| duration))
+        .collect::<Result<Vec<_>, _>>()?;
+    durations.sort();
+
+    Ok([
+        durations[0],
+        durations[durations.len() / 2],
+        durations[durations.len() - 1],
+    ])
+}
+
+fn print_bench_row(day: u8, phase: &str, [min, median, max]: [Duration; 3]) {
+    println!(
+        "{day:>3}  {phase:<6}  {:>12}  {:>12}  {:>12}",
+        format!("{min:.2?}"),
+        format!("{median:.2?}"),
+        format!("{max:.2?}")
+    );
+}
+
+fn bench(solver: &Solver, part: Option<u8>, input: &str, repeat: usize) -> Result<(), AocError> {
+    print_bench_row(
+        solver.day,
+        "parse",
+        measure(repeat, || (solver.parse)(input))?,
+    );
+
+    let parsed = (solver.parse)(input)?;
+    for part_number in [1, 2].into_iter().filter(|&p| part.is_none_or(|q| q == p)) {
+        print_bench_row(
+            solver.day,
+            &format!("part {part_number}"),
+            measure(repeat, || parsed.part(part_number))?,
+        );
+    }
+    Ok(())
+}
+
+fn try_main() -> Result<(), AocError> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            bench: bench_mode,
+            repeat,
+            format,
+        } => {
+            if matches!(day, DaySelection::All) && input.is_some() {
+                return Err(ValueError("--input requires a single day".into()));
+            }
+            let solvers = select_solvers(day)?;
+
+            if bench_mode {
+                println!(
+                    "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                    "day", "phase", "min", "median", "max"
+                );
+                for solver in solvers {
+                    let input = read_input(solver.day, input.as_deref())?;
+                    bench(solver, part, &input, repeat)?;
+                }
+                return Ok(());
+            }
+
+            print_header(format);
+            let failures = solvers
+                .into_iter()
+                .map(|solver| {
+                    let input = read_input(solver.day, input.as_deref());
+                    run(solver, part, input, format)
+                })
+                .sum::<usize>();
+
+            if failures > 0 {
+                return Err(ValueError(format!("{failures} part(s) failed")));
+            }
+        }
+        Command::Verify {
+            day,
+            answers,
+            update,
+        } => verify(&select_solvers(day)?, &answers, update)?,
+        Command::New { day, examples } => new_day(day, examples)?,
+        Command::Trace { day, input, format } => {
+            trace(&read_input(day, input.as_deref())?, format)?
+        }
+        Command::Submit {
+            day,
+            part,
+            input,
+            history,
+            year,
+            base_url,
+            session,
+        } => {
+            let solver =
+                registry::find(day).ok_or(ValueError(format!("No solver for day {day}")))?;
+            let endpoint = Endpoint {
+                base_url: &base_url,
+                year,
+                session: session.as_deref(),
+            };
+            submit(solver, part, input.as_deref(), &endpoint, &history)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
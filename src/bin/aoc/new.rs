@@ -0,0 +1,219 @@
+use advent_of_code_2025::input::example_path;
+use advent_of_code_2025::registry;
+use advent_of_code_2025::AocError;
+use advent_of_code_2025::AocError::ValueError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../../../templates/day.rs.in");
+const FUZZ_TEMPLATE: &str = include_str!("../../../templates/fuzz_target.rs.in");
+const LIB_PATH: &str = "src/lib.rs";
+const REGISTRY_PATH: &str = "src/registry.rs";
+const FUZZ_MANIFEST_PATH: &str = "fuzz/Cargo.toml";
+
+fn module_path(day: u8) -> PathBuf {
+    Path::new("src").join(format!("day{day:02}.rs"))
+}
+
+fn fuzz_target_path(day: u8) -> PathBuf {
+    Path::new("fuzz/fuzz_targets").join(format!("day{day:02}.rs"))
+}
+
+/// Fills in the day template. With `with_examples`, the tests load the
+/// fetched examples, using the second one for part 2 if there is one.
+fn render_day(day: u8, with_examples: bool) -> String {
+    let (example_import, example_part1, example_part2) = if with_examples {
+        let part2_index = if example_path(day, 2).exists() { 2 } else { 1 };
+        (
+            "\n    use crate::input::read_example;".to_string(),
+            format!("&read_example({day}, 1)?"),
+            format!("&read_example({day}, {part2_index})?"),
+        )
+    } else {
+        (String::new(), "\"\"".to_string(), "\"\"".to_string())
+    };
+
+    TEMPLATE
+        .replace("{{day}}", &day.to_string())
+        .replace("{{day02}}", &format!("{day:02}"))
+        .replace("{{example_import}}", &example_import)
+        .replace("{{example_part1}}", &example_part1)
+        .replace("{{example_part2}}", &example_part2)
+}
+
+fn render_fuzz_target(day: u8) -> String {
+    FUZZ_TEMPLATE.replace("{{day02}}", &format!("{day:02}"))
+}
+
+/// Adds `pub mod dayNN;` among the other day modules, keeping them sorted.
+fn register_module(lib: &str, day: u8) -> String {
+    let declaration = format!("pub mod day{day:02};\n");
+    let position = lib
+        .match_indices("pub mod day")
+        .map(|(ix, _)| ix)
+        .find(|&ix| lib[ix..] > *declaration);
+
+    let mut lib = lib.to_string();
+    match position {
+        Some(position) => lib.insert_str(position, &declaration),
+        None => {
+            if !lib.ends_with('\n') {
+                lib.push('\n');
+            }
+            lib.push_str(&declaration);
+        }
+    }
+    lib
+}
+
+/// Adds an entry for the day to `SOLVERS`, keeping the days in order.
+fn register_solver(registry: &str, day: u8) -> Result<String, AocError> {
+    let start = registry
+        .find("pub const SOLVERS")
+        .ok_or(ValueError(format!("no `SOLVERS` in `{REGISTRY_PATH}`")))?;
+    let end = start
+        + registry[start..].find("\n];").ok_or(ValueError(format!(
+            "no end of `SOLVERS` in `{REGISTRY_PATH}`"
+        )))?
+        + 1;
+
+    let day_of_entry = |entry: &str| {
+        let (_, rest) = entry.split_once("::Day")?;
+        rest.get(..2)?.parse::<u8>().ok()
+    };
+    let position = registry[start..end]
+        .match_indices("    Solver::")
+        .map(|(ix, _)| start + ix)
+        .find(|&ix| day_of_entry(&registry[ix..]).is_some_and(|entry_day| entry_day > day))
+        .unwrap_or(end);
+
+    let entry = format!("    Solver::new::<day{day:02}::Day{day:02}>(),\n");
+    let mut registry = registry.to_string();
+    registry.insert_str(position, &entry);
+    Ok(registry)
+}
+
+/// Adds a `[[bin]]` for the day's fuzz target, keeping the days sorted.
+fn register_fuzz_target(manifest: &str, day: u8) -> String {
+    let target = format!(
+        "[[bin]]\nname = \"day{day:02}\"\npath = \"fuzz_targets/day{day:02}.rs\"\ntest = false\ndoc = false\nbench = false\n"
+    );
+    let position = manifest
+        .match_indices("[[bin]]\nname = \"day")
+        .map(|(ix, _)| ix)
+        .find(|&ix| manifest[ix..] > *target);
+
+    let mut manifest = manifest.to_string();
+    match position {
+        Some(position) => manifest.insert_str(position, &format!("{target}\n")),
+        None => {
+            if !manifest.ends_with('\n') {
+                manifest.push('\n');
+            }
+            manifest.push_str(&format!("\n{target}"));
+        }
+    }
+    manifest
+}
+
+/// Creates `src/dayNN.rs` and its fuzz target from the templates, and
+/// registers the day in the library, the solver registry and the fuzz crate.
+pub fn new_day(day: u8, with_examples: bool) -> Result<(), AocError> {
+    let path = module_path(day);
+    if path.exists() || registry::find(day).is_some() {
+        return Err(ValueError(format!("Day {day} already exists")));
+    }
+    if with_examples && !example_path(day, 1).exists() {
+        return Err(ValueError(format!(
+            "no examples in `{}`, fetch them with `just get-input day-{day:02}`",
+            example_path(day, 1).display()
+        )));
+    }
+
+    let lib = register_module(&fs::read_to_string(LIB_PATH)?, day);
+    let registry = register_solver(&fs::read_to_string(REGISTRY_PATH)?, day)?;
+    let fuzz_manifest = register_fuzz_target(&fs::read_to_string(FUZZ_MANIFEST_PATH)?, day);
+
+    fs::write(&path, render_day(day, with_examples))?;
+    fs::write(LIB_PATH, lib)?;
+    fs::write(REGISTRY_PATH, registry)?;
+    fs::write(fuzz_target_path(day), render_fuzz_target(day))?;
+    fs::write(FUZZ_MANIFEST_PATH, fuzz_manifest)?;
+    println!(
+        "Created {} and {}, and registered day {day}",
+        path.display(),
+        fuzz_target_path(day).display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day01::Day01>(),
+    Solver::new::<day12::Day12>(),
+];
+";
+
+    #[test]
+    fn test_register_module() {
+        let lib = "pub use error::AocError;\n\npub mod day01;\npub mod day12;\n";
+        assert_eq!(
+            "pub use error::AocError;\n\npub mod day01;\npub mod day05;\npub mod day12;\n",
+            register_module(lib, 5)
+        );
+        assert_eq!(
+            "pub use error::AocError;\n\npub mod day01;\npub mod day12;\npub mod day13;\n",
+            register_module(lib, 13)
+        );
+    }
+
+    #[test]
+    fn test_register_solver_in_order() -> Result<(), AocError> {
+        let registry = register_solver(REGISTRY, 5)?;
+        let registry = register_solver(&registry, 13)?;
+
+        assert_eq!(
+            "pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day01::Day01>(),
+    Solver::new::<day05::Day05>(),
+    Solver::new::<day12::Day12>(),
+    Solver::new::<day13::Day13>(),
+];
+",
+            registry
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_fuzz_target_in_order() {
+        let manifest = "[dependencies]
+libfuzzer-sys = \"0.4\"
+
+[[bin]]
+name = \"day01\"
+path = \"fuzz_targets/day01.rs\"
+test = false
+doc = false
+bench = false
+";
+        let manifest = register_fuzz_target(manifest, 13);
+        let manifest = register_fuzz_target(&manifest, 5);
+
+        let names = manifest
+            .lines()
+            .filter(|line| line.starts_with("name = "))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["name = \"day01\"", "name = \"day05\"", "name = \"day13\""],
+            names
+        );
+        assert!(manifest.contains("\nbench = false\n\n[[bin]]\nname = \"day05\"\n"));
+        assert!(manifest.ends_with(
+            "path = \"fuzz_targets/day13.rs\"\ntest = false\ndoc = false\nbench = false\n"
+        ));
+    }
+}
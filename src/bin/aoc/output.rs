@@ -0,0 +1,122 @@
+use advent_of_code_2025::Answer;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// human readable lines
+    Text,
+    /// one JSON object per line
+    Json,
+    /// comma separated values with a header row
+    Csv,
+}
+
+/// Outcome of running one part of one day.
+#[derive(Serialize, Debug)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// time spent parsing the input, which both parts share
+    pub parse_ns: Option<u64>,
+    pub duration_ns: Option<u64>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(
+        day: u8,
+        part: u8,
+        input_hash: Option<&str>,
+        parse_duration: Option<Duration>,
+        result: Result<(Answer, Duration), String>,
+    ) -> Self {
+        let (answer, duration_ns, error) = match result {
+            Ok((answer, duration)) => (Some(answer), Some(duration.as_nanos() as u64), None),
+            Err(error) => (None, None, Some(error)),
+        };
+        Record {
+            day,
+            part,
+            answer,
+            parse_ns: parse_duration.map(|duration| duration.as_nanos() as u64),
+            duration_ns,
+            input_hash: input_hash.map(str::to_string),
+            error,
+        }
+    }
+}
+
+/// Hex encoded SHA-256 of the input, to tell apart answers for different inputs.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn print_header(format: Format) {
+    if format == Format::Csv {
+        println!("day,part,answer,parse_ns,duration_ns,input_hash,error");
+    }
+}
+
+pub fn print_record(format: Format, record: &Record) {
+    match format {
+        Format::Text => match (&record.answer, record.duration_ns, &record.error) {
+            (Some(Answer::Unsolved), _, _) => println!("Part {}: not solved", record.part),
+            (Some(answer), Some(duration_ns), _) => println!(
+                "Part {}: {answer} ({:.2?})",
+                record.part,
+                Duration::from_nanos(duration_ns)
+            ),
+            (_, _, error) => println!(
+                "Part {}: {}",
+                record.part,
+                error.as_deref().unwrap_or_default()
+            ),
+        },
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(record).expect("records are always serialisable")
+        ),
+        Format::Csv => println!(
+            "{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            csv_field(record.answer.as_ref().map(Answer::to_string).as_deref()),
+            record.parse_ns.map_or(String::new(), |d| d.to_string()),
+            record.duration_ns.map_or(String::new(), |d| d.to_string()),
+            csv_field(record.input_hash.as_deref()),
+            csv_field(record.error.as_deref()),
+        ),
+    }
+}
+
+fn csv_field(value: Option<&str>) -> String {
+    match value {
+        Some(value) if value.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!("", csv_field(None));
+        assert_eq!("1227775554", csv_field(Some("1227775554")));
+        assert_eq!(
+            "\"expected \"\"tag\"\",\n at line 2\"",
+            csv_field(Some("expected \"tag\",\n at line 2"))
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use advent_of_code_2025::input::read_input;
+use advent_of_code_2025::registry::Solver;
+use advent_of_code_2025::Answer;
+use advent_of_code_2025::AocError;
+use advent_of_code_2025::AocError::ValueError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Cooldown after a wrong answer when the response does not say how long it is.
+const DEFAULT_WAIT_SECS: u64 = 60;
+
+/// Where and as whom answers are submitted.
+pub struct Endpoint<'a> {
+    pub base_url: &'a str,
+    pub year: u32,
+    pub session: Option<&'a str>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// an answer was submitted too recently
+    Wait,
+    /// the part is already solved, or part 1 is not solved yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    fn describe(self) -> &'static str {
+        match self {
+            Outcome::Right => "is the right answer",
+            Outcome::Wrong => "is not the right answer",
+            Outcome::TooHigh => "is too high",
+            Outcome::TooLow => "is too low",
+            Outcome::Wait => "was not checked, an answer was submitted too recently",
+            Outcome::WrongLevel => "was not checked, the part is locked or already solved",
+            Outcome::Unknown => "got a response that was not understood",
+        }
+    }
+
+    fn is_rejection(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+/// One answer sent to the site, with what it said about it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// seconds since the Unix epoch
+    pub submitted_at: u64,
+    /// no answer for the day is sent before this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+/// Every answer submitted so far, oldest first.
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Loads the history file, treating a missing file as an empty history.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| ValueError(format!("invalid history file `{}`: {e}", path.display()))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).map_err(|e| ValueError(e.to_string()))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Explains why `answer` must not be submitted, if the history already
+    /// tells what the site would say about it or the site asked us to wait.
+    pub fn refusal(&self, year: u32, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        let same_day = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day);
+
+        if let Some(wait_until) = same_day.clone().filter_map(|s| s.wait_until).max() {
+            if now < wait_until {
+                return Some(format!(
+                    "wait {}s before submitting again",
+                    wait_until - now
+                ));
+            }
+        }
+
+        let answer_value = answer.parse::<i128>().ok();
+        for s in same_day.filter(|s| s.part == part) {
+            let bound = answer_value.zip(s.answer.parse::<i128>().ok());
+            match (s.outcome, bound) {
+                (Outcome::Right, _) => {
+                    return Some(format!(
+                        "part {part} was already solved with `{}`",
+                        s.answer
+                    ))
+                }
+                (outcome, _) if outcome.is_rejection() && s.answer == answer => {
+                    return Some(format!("`{answer}` {}", outcome.describe()))
+                }
+                (Outcome::TooHigh, Some((new, old))) if new >= old => {
+                    return Some(format!(
+                        "`{answer}` is not below `{old}`, which is too high"
+                    ))
+                }
+                (Outcome::TooLow, Some((new, old))) if new <= old => {
+                    return Some(format!("`{answer}` is not above `{old}`, which is too low"))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+}
+
+/// Reads the outcome, and how many seconds to wait before the next answer,
+/// from the page the site answers a submission with.
+pub fn parse_response(body: &str) -> (Outcome, Option<u64>) {
+    let text = body.to_lowercase();
+
+    if text.contains("that's the right answer") {
+        (Outcome::Right, None)
+    } else if text.contains("that's not the right answer") {
+        let outcome = if text.contains("answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        };
+        let wait = text
+            .split_once("please wait ")
+            .and_then(|(_, rest)| {
+                let (amount, rest) = rest.split_once(' ')?;
+                let amount = match amount {
+                    "one" => 1,
+                    amount => amount.parse().ok()?,
+                };
+                rest.starts_with("minute").then_some(amount * 60)
+            })
+            .unwrap_or(DEFAULT_WAIT_SECS);
+        (outcome, Some(wait))
+    } else if text.contains("you gave an answer too recently") {
+        let wait = text
+            .split_once("you have ")
+            .and_then(|(_, rest)| parse_wait(rest.split_once(" left to wait")?.0));
+        (Outcome::Wait, Some(wait.unwrap_or(DEFAULT_WAIT_SECS)))
+    } else if text.contains("you don't seem to be solving the right level") {
+        (Outcome::WrongLevel, None)
+    } else {
+        (Outcome::Unknown, None)
+    }
+}
+
+/// Parses durations such as `39s` or `1m 29s`.
+fn parse_wait(text: &str) -> Option<u64> {
+    text.split_whitespace().try_fold(0, |total, component| {
+        let (amount, unit) = component.split_at(component.len().checked_sub(1)?);
+        let amount = amount.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(total + amount * 3600),
+            "m" => Some(total + amount * 60),
+            "s" => Some(total + amount),
+            _ => None,
+        }
+    })
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs())
+}
+
+fn post_answer(endpoint: &Endpoint, day: u8, part: u8, answer: &str) -> Result<String, AocError> {
+    let session = endpoint
+        .session
+        .ok_or(ValueError("SESSION must be set to submit answers".into()))?;
+    let url = format!(
+        "{}/{}/day/{day}/answer",
+        endpoint.base_url.trim_end_matches('/'),
+        endpoint.year
+    );
+
+    ureq::post(&url)
+        .header("Cookie", format!("session={session}"))
+        .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+        .and_then(|response| response.into_body().read_to_string())
+        .map_err(|e| ValueError(format!("cannot submit to `{url}`: {e}")))
+}
+
+/// Runs `part` of `solver` on its real input and submits the answer, unless
+/// the history shows that it would be rejected or that it is too early.
+pub fn submit(
+    solver: &Solver,
+    part: u8,
+    input_path: Option<&Path>,
+    endpoint: &Endpoint,
+    history_path: &Path,
+) -> Result<(), AocError> {
+    let input = read_input(solver.day, input_path)?;
+    let answer = match (solver.parse)(&input)?.part(part)? {
+        Answer::Unsolved => {
+            return Err(ValueError(format!(
+                "Day {:02} part {part} is not solved",
+                solver.day
+            )))
+        }
+        answer => answer.to_string(),
+    };
+
+    let mut history = History::load(history_path)?;
+    let submitted_at = now();
+    if let Some(refusal) = history.refusal(endpoint.year, solver.day, part, &answer, submitted_at) {
+        return Err(ValueError(format!(
+            "Day {:02} part {part}: not submitted, {refusal}",
+            solver.day
+        )));
+    }
+
+    let (outcome, wait) = parse_response(&post_answer(endpoint, solver.day, part, &answer)?);
+    history.record(Submission {
+        year: endpoint.year,
+        day: solver.day,
+        part,
+        answer: answer.clone(),
+        outcome,
+        submitted_at,
+        wait_until: wait.map(|wait| submitted_at + wait),
+    });
+    history.save(history_path)?;
+
+    let message = format!(
+        "Day {:02} part {part}: `{answer}` {}",
+        solver.day,
+        outcome.describe()
+    );
+    match outcome {
+        Outcome::Right => {
+            println!("{message}");
+            Ok(())
+        }
+        _ => Err(ValueError(message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn submission(part: u8, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2025,
+            day: 1,
+            part,
+            answer: answer.into(),
+            outcome,
+            submitted_at: 1_000,
+            wait_until: outcome.is_rejection().then_some(1_060),
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            (Outcome::Right, None),
+            parse_response("<article><p>That's the right answer! You are one gold star closer")
+        );
+        assert_eq!(
+            (Outcome::TooHigh, Some(60)),
+            parse_response(
+                "That's not the right answer; your answer is too high. \
+                 Please wait one minute before trying again."
+            )
+        );
+        assert_eq!(
+            (Outcome::Wrong, Some(300)),
+            parse_response(
+                "That's not the right answer. Because you have guessed incorrectly 4 \
+                 times on this puzzle, please wait 5 minutes before trying again."
+            )
+        );
+        assert_eq!(
+            (Outcome::Wait, Some(89)),
+            parse_response("You gave an answer too recently; you have 1m 29s left to wait.")
+        );
+        assert_eq!(
+            (Outcome::WrongLevel, None),
+            parse_response("You don't seem to be solving the right level.")
+        );
+    }
+
+    #[test]
+    fn test_refuses_known_answers() {
+        let mut history = History::default();
+        history.record(submission(1, "1200", Outcome::TooHigh));
+        history.record(submission(1, "900", Outcome::TooLow));
+
+        assert!(history.refusal(2025, 1, 1, "1000", 1_030).is_some());
+        assert_eq!(None, history.refusal(2025, 1, 1, "1000", 1_060));
+        assert_eq!(None, history.refusal(2024, 1, 1, "1200", 1_030));
+        assert_eq!(
+            Some("`1200` is too high".to_string()),
+            history.refusal(2025, 1, 1, "1200", 1_060)
+        );
+        assert!(history.refusal(2025, 1, 1, "1300", 1_060).is_some());
+        assert!(history.refusal(2025, 1, 1, "850", 1_060).is_some());
+
+        history.record(submission(1, "1000", Outcome::Right));
+        assert!(history.refusal(2025, 1, 1, "1100", 1_060).is_some());
+        assert_eq!(None, history.refusal(2025, 1, 2, "1200", 1_060));
+    }
+
+    #[test]
+    fn test_post_answer() -> Result<(), AocError> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"answer=42") {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+            }
+            let body = "That's the right answer!";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let endpoint = Endpoint {
+            base_url: &base_url,
+            year: 2025,
+            session: Some("c00k1e"),
+        };
+        let body = post_answer(&endpoint, 3, 2, "42")?;
+
+        let request = server.join().unwrap();
+        assert_eq!((Outcome::Right, None), parse_response(&body));
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.1"));
+        assert!(request
+            .to_lowercase()
+            .contains("cookie: session=c00k1e\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=42"));
+        Ok(())
+    }
+}
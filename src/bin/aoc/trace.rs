@@ -0,0 +1,138 @@
+use crate::output::Format;
+use advent_of_code_2025::day01::{parse_numbered, Dial, Turn};
+use advent_of_code_2025::AocError;
+use serde::Serialize;
+
+/// One turn of the day 1 dial, with the running total of part 2.
+#[derive(Serialize, Debug, PartialEq)]
+struct TraceRow {
+    line: usize,
+    turn: String,
+    virtual_position: i64,
+    position: i64,
+    zero_clicks: i64,
+    total: i64,
+}
+
+fn trace_rows(input: &str) -> Result<Vec<TraceRow>, AocError> {
+    let (lines, turns): (Vec<usize>, Vec<Turn>) = parse_numbered(input)?.into_iter().unzip();
+    let mut total = 0;
+
+    Dial::default()
+        .turns(&turns)
+        .zip(lines.into_iter().zip(&turns))
+        .map(|(event, (line, turn))| {
+            let event = event?;
+            total += event.passes;
+            Ok(TraceRow {
+                line,
+                turn: turn.to_string(),
+                virtual_position: event.virtual_position,
+                position: event.position,
+                zero_clicks: event.passes,
+                total,
+            })
+        })
+        .collect()
+}
+
+fn header(format: Format) -> Option<String> {
+    match format {
+        Format::Text => Some(format!(
+            "{:>5}  {:>8}  {:>8}  {:>8}  {:>6}  {:>6}",
+            "line", "turn", "virtual", "position", "zeros", "total"
+        )),
+        Format::Csv => Some("line,turn,virtual_position,position,zero_clicks,total".into()),
+        Format::Json => None,
+    }
+}
+
+fn format_row(format: Format, row: &TraceRow) -> String {
+    match format {
+        Format::Text => format!(
+            "{:>5}  {:>8}  {:>8}  {:>8}  {:>6}  {:>6}",
+            row.line, row.turn, row.virtual_position, row.position, row.zero_clicks, row.total
+        ),
+        Format::Json => serde_json::to_string(row).expect("rows are always serialisable"),
+        Format::Csv => format!(
+            "{},{},{},{},{},{}",
+            row.line, row.turn, row.virtual_position, row.position, row.zero_clicks, row.total
+        ),
+    }
+}
+
+/// Prints how every turn of the day 1 input moves the dial and how often it
+/// passes zero.
+pub fn trace(input: &str, format: Format) -> Result<(), AocError> {
+    let rows = trace_rows(input)?;
+
+    if let Some(header) = header(format) {
+        println!("{header}");
+    }
+    for row in &rows {
+        println!("{}", format_row(format, row));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code_2025::input::read_example;
+
+    #[test]
+    fn test_trace_rows() -> Result<(), AocError> {
+        let rows = trace_rows(&read_example(1, 1)?)?;
+
+        assert_eq!(
+            TraceRow {
+                line: 1,
+                turn: "L68".into(),
+                virtual_position: -18,
+                position: 82,
+                zero_clicks: 1,
+                total: 1,
+            },
+            rows[0]
+        );
+        assert_eq!(6, rows.last().map_or(0, |row| row.total));
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace_rows_keep_line_numbers() -> Result<(), AocError> {
+        let rows = trace_rows("# start at 50\n\n2x(R25) =10\n")?;
+
+        let lines_and_turns = rows
+            .iter()
+            .map(|row| (row.line, row.turn.as_str(), row.position))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(3, "R25", 75), (3, "R25", 0), (3, "=10", 10)],
+            lines_and_turns
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_row() {
+        let row = TraceRow {
+            line: 2,
+            turn: "L30".into(),
+            virtual_position: 52,
+            position: 52,
+            zero_clicks: 0,
+            total: 1,
+        };
+
+        assert_eq!(
+            "    2       L30        52        52       0       1",
+            format_row(Format::Text, &row)
+        );
+        assert_eq!("2,L30,52,52,0,1", format_row(Format::Csv, &row));
+        assert_eq!(
+            r#"{"line":2,"turn":"L30","virtual_position":52,"position":52,"zero_clicks":0,"total":1}"#,
+            format_row(Format::Json, &row)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use advent_of_code_2025::input::read_input;
+use advent_of_code_2025::registry::Solver;
+use advent_of_code_2025::Answer;
+use advent_of_code_2025::AocError;
+use advent_of_code_2025::AocError::ValueError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const HEADER: &str = "\
+# Accepted answers on the real inputs, checked by `aoc verify`.
+# Record new ones with `aoc verify <day> --update`.
+";
+
+/// Accepted answers, keyed by `day-NN` and then by `part1`/`part2`.
+#[derive(Serialize, Deserialize, Default, Debug)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl DayAnswers {
+    fn part_mut(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as having no answers.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| ValueError(format!("invalid answers file `{}`: {e}", path.display()))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).map_err(|e| ValueError(e.to_string()))?;
+        fs::write(path, format!("{HEADER}\n{content}"))?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.0.get(&format!("day-{day:02}"))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: String) {
+        *self
+            .0
+            .entry(format!("day-{day:02}"))
+            .or_default()
+            .part_mut(part) = Some(answer);
+    }
+}
+
+/// Runs the solvers against their real input and compares the answers with
+/// the recorded ones. With `update`, the current answers are recorded instead.
+pub fn verify(solvers: &[&Solver], answers_path: &Path, update: bool) -> Result<(), AocError> {
+    let mut answers = Answers::load(answers_path)?;
+    let mut failures = 0;
+    let mut checked = 0;
+
+    for solver in solvers {
+        let parts = [1, 2]
+            .into_iter()
+            .filter(|&part| update || answers.get(solver.day, part).is_some())
+            .collect::<Vec<_>>();
+
+        if parts.is_empty() {
+            continue;
+        }
+        checked += parts.len();
+
+        let input = match read_input(solver.day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {:02}: {e}", solver.day);
+                failures += parts.len();
+                continue;
+            }
+        };
+        let parsed = match (solver.parse)(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("Day {:02}: {e}", solver.day);
+                failures += parts.len();
+                continue;
+            }
+        };
+
+        for part in parts {
+            let actual = match parsed.part(part) {
+                Ok(actual) => actual,
+                Err(e) => {
+                    println!("Day {:02} part {part}: {e}", solver.day);
+                    failures += 1;
+                    continue;
+                }
+            };
+
+            match answers.get(solver.day, part) {
+                Some(expected) if expected == actual.to_string() => {
+                    println!("Day {:02} part {part}: ok", solver.day);
+                }
+                _ if update && actual == Answer::Unsolved => {
+                    println!("Day {:02} part {part}: not solved", solver.day);
+                }
+                _ if update => {
+                    println!("Day {:02} part {part}: recorded {actual}", solver.day);
+                    answers.set(solver.day, part, actual.to_string());
+                }
+                Some(expected) => {
+                    println!("Day {:02} part {part}: changed", solver.day);
+                    println!("  - {expected}");
+                    println!("  + {actual}");
+                    failures += 1;
+                }
+                None => {}
+            }
+        }
+    }
+
+    if update {
+        answers.save(answers_path)?;
+    }
+
+    if checked == 0 {
+        println!("No accepted answers in `{}`", answers_path.display());
+    }
+
+    if failures > 0 {
+        return Err(ValueError(format!("{failures} answer(s) did not verify")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_round_trip() -> Result<(), toml::de::Error> {
+        let mut answers: Answers = toml::from_str("[day-05]\npart1 = \"3\"\n")?;
+        answers.set(5, 2, "14".into());
+        answers.set(12, 1, "2".into());
+
+        assert_eq!(Some("3"), answers.get(5, 1));
+        assert_eq!(None, answers.get(12, 2));
+        assert_eq!(
+            "[day-05]\npart1 = \"3\"\npart2 = \"14\"\n\n[day-12]\npart1 = \"2\"\n",
+            toml::to_string(&answers).unwrap()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,508 @@
+use crate::day01::Turn::{Left, Right, Set};
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, i32, space0, space1, usize};
+use nom::combinator::cut;
+use nom::error::ErrorKind;
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, preceded, terminated};
+use nom::{IResult, Parser};
+use std::fmt;
+use std::str::FromStr;
+
+/// Repeat blocks may not expand an input to more turns than this.
+const MAX_TURNS: usize = 1_000_000;
+/// nor be nested deeper than this.
+const MAX_NESTING: usize = 16;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Turn {
+    Left(i32),
+    Right(i32),
+    /// jumps to a position on the dial without turning. Landing on the
+    /// target counts for part 1, but no click passes it, so `=0` adds nothing
+    /// to part 2.
+    Set(i32),
+}
+
+fn turn(input: &str) -> IResult<&str, Turn> {
+    alt((
+        preceded(char('L'), i32).map(Left),
+        preceded(char('R'), i32).map(Right),
+        preceded(char('='), i32).map(Set),
+    ))
+    .parse(input)
+}
+
+enum Instruction {
+    Turn(Turn),
+    /// a block such as `3x(R10 L5)` repeating the instructions inside the
+    /// parentheses
+    Repeat(usize, Vec<Instruction>),
+}
+
+fn instruction(input: &str, depth: usize) -> IResult<&str, Instruction> {
+    alt((
+        turn.map(Instruction::Turn),
+        (
+            terminated(usize, tag("x(")),
+            cut(terminated(
+                |input| instructions(input, depth + 1),
+                char(')'),
+            )),
+        )
+            .map(|(times, body)| Instruction::Repeat(times, body)),
+    ))
+    .parse(input)
+}
+
+/// Instructions separated by spaces, inside `depth` repeat blocks.
+fn instructions(input: &str, depth: usize) -> IResult<&str, Vec<Instruction>> {
+    if depth > MAX_NESTING {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            ErrorKind::TooLarge,
+        )));
+    }
+    delimited(
+        space0,
+        separated_list0(space1, |input| instruction(input, depth)),
+        space0,
+    )
+    .parse(input)
+}
+
+/// Appends the turns of `instructions` to `turns`, failing as soon as there
+/// are more than `max_turns` of them.
+fn expand(
+    instructions: &[Instruction],
+    turns: &mut Vec<Turn>,
+    max_turns: usize,
+) -> Result<(), AocError> {
+    for instruction in instructions {
+        match instruction {
+            Instruction::Turn(turn) => turns.push(*turn),
+            Instruction::Repeat(times, body) => {
+                let mut body_turns = Vec::new();
+                expand(body, &mut body_turns, max_turns)?;
+                for _ in 0..*times {
+                    if body_turns.is_empty() || turns.len() > max_turns {
+                        break;
+                    }
+                    turns.extend_from_slice(&body_turns);
+                }
+            }
+        }
+        if turns.len() > max_turns {
+            return Err(ValueError(format!(
+                "Input expands to more than {MAX_TURNS} turns"
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl FromStr for Turn {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(s, turn)
+    }
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Left(n) => write!(f, "L{n}"),
+            Right(n) => write!(f, "R{n}"),
+            Set(n) => write!(f, "={n}"),
+        }
+    }
+}
+
+/// The turns of the input, each with the number of the line it is on. A line
+/// holds any number of instructions separated by spaces, optionally followed
+/// by a `#` comment.
+pub fn parse_numbered(input: &str) -> Result<Vec<(usize, Turn)>, AocError> {
+    let mut turns = Vec::new();
+    let mut line_offset = 0;
+
+    for (ix, line) in input.split('\n').enumerate() {
+        let code = line
+            .split_once('#')
+            .map_or(line, |(code, _)| code)
+            .trim_end_matches('\r');
+        let error_at = |rest: &str, expected: &str| {
+            let offset = line_offset + code.len() - rest.len();
+            AocError::parse_error(input, &input[offset..], expected)
+        };
+
+        match (|code| instructions(code, 0)).parse_complete(code) {
+            Ok(("", line_instructions)) => {
+                let mut line_turns = Vec::new();
+                expand(&line_instructions, &mut line_turns, MAX_TURNS - turns.len())?;
+                turns.extend(line_turns.into_iter().map(|turn| (ix + 1, turn)));
+            }
+            Ok((rest, _)) => return Err(error_at(rest, "instruction")),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                let expected = match e.code {
+                    ErrorKind::Char => "instruction or `)`",
+                    ErrorKind::TooLarge => "fewer nested repeat blocks",
+                    _ => "instruction",
+                };
+                return Err(error_at(e.input, expected));
+            }
+            Err(nom::Err::Incomplete(_)) => return Err(error_at("", "more input")),
+        }
+
+        line_offset += line.len() + 1;
+    }
+
+    Ok(turns)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Turn>, AocError> {
+    Ok(parse_numbered(input)?
+        .into_iter()
+        .map(|(_, turn)| turn)
+        .collect())
+}
+
+/// What happened during one turn of a [`Dial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnEvent {
+    /// where the dial would point if it did not wrap around, counted from
+    /// where it pointed before the turn
+    pub virtual_position: i64,
+    /// where the dial points after the turn
+    pub position: i64,
+    /// how many clicks of the turn left the dial pointing at the target,
+    /// including the last one
+    pub passes: i64,
+    /// whether the turn ended on the target
+    pub lands_on_target: bool,
+}
+
+/// A dial with positions `0..size`, counting how often it points at
+/// `target`. The default is the puzzle's dial: 100 positions, starting at 50,
+/// with 0 as the target.
+#[derive(Debug, Clone)]
+pub struct Dial {
+    size: i64,
+    position: i64,
+    target: i64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            size: 100,
+            position: 50,
+            target: 0,
+        }
+    }
+}
+
+/// `position`, if it is one of the positions of a dial of `size`.
+fn on_dial(position: i64, size: i64) -> Result<i64, AocError> {
+    if (0..size).contains(&position) {
+        Ok(position)
+    } else {
+        Err(ValueError(format!(
+            "Position {position} is not on a dial of size {size}"
+        )))
+    }
+}
+
+impl Dial {
+    pub fn new(size: i64, start: i64, target: i64) -> Result<Self, AocError> {
+        if size <= 0 {
+            return Err(ValueError(format!(
+                "Dial size must be positive, not {size}"
+            )));
+        }
+
+        Ok(Dial {
+            size,
+            position: on_dial(start, size)?,
+            target: on_dial(target, size)?,
+        })
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// Turns the dial, failing if `turn` sets it to a position off the dial.
+    pub fn turn(&mut self, turn: &Turn) -> Result<TurnEvent, AocError> {
+        let clicks = match *turn {
+            Left(n) => -i64::from(n),
+            Right(n) => i64::from(n),
+            Set(n) => {
+                self.position = on_dial(n.into(), self.size)?;
+                return Ok(TurnEvent {
+                    virtual_position: n.into(),
+                    position: self.position,
+                    passes: 0,
+                    lands_on_target: self.position == self.target,
+                });
+            }
+        };
+
+        // Clicks until the target, counted in the direction of the turn.
+        let to_target = if clicks >= 0 {
+            self.target - self.position
+        } else {
+            self.position - self.target
+        }
+        .rem_euclid(self.size);
+        let to_target = if to_target == 0 { self.size } else { to_target };
+
+        let passes = match clicks.abs() - to_target {
+            remaining @ 0.. => 1 + remaining / self.size,
+            _ => 0,
+        };
+
+        let virtual_position = self.position + clicks;
+        self.position = virtual_position.rem_euclid(self.size);
+        Ok(TurnEvent {
+            virtual_position,
+            position: self.position,
+            passes,
+            lands_on_target: self.position == self.target,
+        })
+    }
+
+    /// The events of the turns, one after another.
+    pub fn turns<'a>(
+        mut self,
+        turns: &'a [Turn],
+    ) -> impl Iterator<Item = Result<TurnEvent, AocError>> + 'a {
+        turns.iter().map(move |turn| self.turn(turn))
+    }
+}
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = Vec<Turn>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, turns: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result = Dial::default()
+            .turns(turns)
+            .filter_ok(|event| event.lands_on_target)
+            .process_results(|events| events.count())?;
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, turns: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result = Dial::default()
+            .turns(turns)
+            .map_ok(|event| event.passes)
+            .sum::<Result<i64, AocError>>()?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+    use proptest::prelude::*;
+
+    /// The passes of each turn, found by moving the dial one click at a time,
+    /// or `None` if a turn sets the dial to a position it doesn't have.
+    fn simulate_clicks(size: i64, start: i64, target: i64, turns: &[Turn]) -> Option<Vec<i64>> {
+        let mut pos = start;
+        turns
+            .iter()
+            .map(|turn| {
+                let (step, clicks) = match *turn {
+                    Left(n) => (-1, n),
+                    Right(n) => (1, n),
+                    Set(n) if n >= 0 && i64::from(n) < size => {
+                        pos = n.into();
+                        (0, 0)
+                    }
+                    Set(_) => return None,
+                };
+                let mut passes = 0;
+                for _ in 0..clicks {
+                    pos = (pos + step).rem_euclid(size);
+                    if pos == target {
+                        passes += 1;
+                    }
+                }
+                Some(passes)
+            })
+            .collect()
+    }
+
+    /// Turns of up to 500 clicks, and now and then a set to a position
+    /// around a dial of `size`.
+    fn turn(size: i32) -> impl Strategy<Value = Turn> {
+        prop_oneof![
+            4 => (0..500).prop_map(Left),
+            4 => (0..500).prop_map(Right),
+            1 => (-2..size + 2).prop_map(Set),
+        ]
+    }
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(3, Day01.solve_part1(&read_example(1, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(6, Day01.solve_part2(&read_example(1, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dial_passes_other_targets() -> Result<(), AocError> {
+        let turns = parse_input(&read_example(1, 1)?)?;
+        let passes = Dial::new(100, 50, 37)?
+            .turns(&turns)
+            .map_ok(|event| event.passes)
+            .sum::<Result<i64, AocError>>()?;
+
+        assert_eq!(
+            simulate_clicks(100, 50, 37, &turns).map(|passes| passes.iter().sum::<i64>()),
+            Some(passes)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_extended_instructions() -> Result<(), AocError> {
+        let input = "# warm up\nL68\n\n=0  # reset\n2x(R10 3x(L1)) R5\n";
+        let turns = parse_numbered(input)?
+            .into_iter()
+            .map(|(line, turn)| (line, turn.to_string()))
+            .collect::<Vec<_>>();
+
+        let expected = [
+            (2, "L68"),
+            (4, "=0"),
+            (5, "R10"),
+            (5, "L1"),
+            (5, "L1"),
+            (5, "L1"),
+            (5, "R10"),
+            (5, "L1"),
+            (5, "L1"),
+            (5, "L1"),
+            (5, "R5"),
+        ]
+        .map(|(line, turn)| (line, turn.to_string()));
+        assert_eq!(expected.to_vec(), turns);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_reports_line_of_unknown_instruction() {
+        for (input, expected_line, expected_column) in [
+            ("L1\nR2\nX3\n", 3, 1),
+            ("L1\n\n# comment\nR2 U3", 4, 4),
+            ("R1\n2x(R10 Q5)", 2, 8),
+            ("R1\n  2x(R10", 2, 9),
+        ] {
+            let error = parse_input(input).unwrap_err();
+            assert!(
+                matches!(
+                    error,
+                    AocError::ParseError { line, column, .. }
+                        if (line, column) == (expected_line, expected_column)
+                ),
+                "{input:?}: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_too_many_turns() {
+        assert!(parse_input("1000x(1000x(R1))\nL1").is_err());
+        assert!(parse_input("18446744073709551615x(R1 L1)").is_err());
+        assert!(parse_input("18446744073709551615x()").is_ok());
+
+        let depth = 1000;
+        let nested = format!("{}R1{}", "1x(".repeat(depth), ")".repeat(depth));
+        assert!(parse_input(&nested).is_err());
+    }
+
+    #[test]
+    fn test_set_jumps_without_clicks() -> Result<(), AocError> {
+        let events = Dial::default()
+            .turns(&parse_input("=0\n=99\nR1")?)
+            .map_ok(|event| (event.position, event.passes, event.lands_on_target))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(vec![(0, 0, true), (99, 0, false), (0, 1, true)], events);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dial_rejects_positions_off_the_dial() {
+        assert!(Dial::new(0, 0, 0).is_err());
+        assert!(Dial::new(10, 10, 0).is_err());
+        assert!(Dial::new(10, 0, -1).is_err());
+        assert!(Dial::default().turn(&Set(100)).is_err());
+        assert!(Dial::default().turn(&Set(-1)).is_err());
+        assert!(Day01.solve_part1("=150").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_part2_matches_simulation(turns in prop::collection::vec(turn(100), 0..50)) {
+            match simulate_clicks(100, 50, 0, &turns) {
+                Some(passes) => prop_assert_eq!(passes.iter().sum::<i64>(), Day01.part2(&turns)?),
+                None => prop_assert!(Day01.part2(&turns).is_err()),
+            }
+        }
+
+        #[test]
+        fn test_dial_matches_simulation(
+            (size, start, target, turns) in (1..30_i32).prop_flat_map(|size| {
+                (
+                    Just(i64::from(size)),
+                    0..i64::from(size),
+                    0..i64::from(size),
+                    prop::collection::vec(turn(size), 0..20),
+                )
+            }),
+        ) {
+            let events = Dial::new(size, start, target)?
+                .turns(&turns)
+                .collect::<Result<Vec<_>, _>>();
+            match (simulate_clicks(size, start, target, &turns), events) {
+                (Some(passes), Ok(events)) => {
+                    prop_assert_eq!(
+                        passes,
+                        events.iter().map(|event| event.passes).collect::<Vec<_>>()
+                    );
+                    for event in events {
+                        prop_assert_eq!(event.position == target, event.lands_on_target);
+                    }
+                }
+                (passes, events) => prop_assert!(passes.is_none() && events.is_err()),
+            }
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use itertools::Itertools;
+
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use std::ops::RangeInclusive;
+
+pub fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, AocError> {
+    parse_all(
+        input,
+        separated_list0(
+            tag(","),
+            separated_pair(complete::u64, tag("-"), complete::u64),
+        ),
+    )
+}
+
+/// Which numbers count as a repeated pattern: those whose digits in `radix`
+/// are one block, without leading zeros, written `times` times in a row for a
+/// `times` between `min_times` and `max_times`. A number can repeat several
+/// blocks, 1111 repeats 11 twice and 1 four times, and matches if any of them
+/// is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repetition {
+    radix: u32,
+    min_times: u32,
+    max_times: u32,
+}
+
+impl Repetition {
+    /// A block written exactly `times` times, in decimal.
+    pub fn exactly(times: u32) -> Self {
+        Repetition {
+            radix: 10,
+            min_times: times,
+            max_times: times,
+        }
+    }
+
+    /// A block written `times` times or more, in decimal.
+    pub fn at_least(times: u32) -> Self {
+        Repetition {
+            radix: 10,
+            min_times: times,
+            max_times: u32::MAX,
+        }
+    }
+
+    /// A block written between `min_times` and `max_times` times, in decimal.
+    pub fn between(min_times: u32, max_times: u32) -> Self {
+        Repetition {
+            radix: 10,
+            min_times,
+            max_times,
+        }
+    }
+
+    /// The same rule for the digits in another radix.
+    pub fn in_radix(self, radix: u32) -> Result<Self, AocError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ValueError(format!("Radix {radix} is not between 2 and 36")));
+        }
+        Ok(Repetition { radix, ..self })
+    }
+
+    /// The number of digits of `n`.
+    fn digits(&self, n: u128) -> u32 {
+        n.checked_ilog(self.radix.into()).map_or(1, |log| log + 1)
+    }
+
+    /// How often a block may be written in a number of `len` digits.
+    fn allowed_times(&self, len: u32) -> impl Iterator<Item = u32> {
+        (self.min_times.max(1)..=self.max_times.min(len))
+            .filter(move |&times| len.is_multiple_of(times))
+    }
+
+    /// The number that repeats a block of `block_len` digits `times` times when
+    /// multiplied with it, such as 10101 for 2 digits written 3 times in decimal.
+    fn multiplier(&self, block_len: u32, times: u32) -> u128 {
+        (0..times)
+            .map(|i| u128::from(self.radix).pow(block_len * i))
+            .sum()
+    }
+
+    /// The blocks of `block_len` digits whose repetition lies in
+    /// `start..=end`.
+    fn blocks(&self, start: u64, end: u64, block_len: u32, times: u32) -> RangeInclusive<u128> {
+        let radix = u128::from(self.radix);
+        let multiplier = self.multiplier(block_len, times);
+        let lowest = radix
+            .pow(block_len - 1)
+            .max(u128::from(start).div_ceil(multiplier));
+        let highest = (radix.pow(block_len) - 1).min(u128::from(end) / multiplier);
+        lowest..=highest
+    }
+
+    /// The sum of the numbers in `start..=end` repeating a block of
+    /// `block_len` digits `times` times, without enumerating them.
+    fn sum_of_blocks(&self, start: u64, end: u64, block_len: u32, times: u32) -> u128 {
+        let blocks = self.blocks(start, end, block_len, times);
+        if blocks.is_empty() {
+            return 0;
+        }
+
+        let (lowest, highest) = (*blocks.start(), *blocks.end());
+        (lowest + highest) * (highest - lowest + 1) / 2 * self.multiplier(block_len, times)
+    }
+
+    /// Whether `n` matches. Zero never does, having no digits without
+    /// leading zeros.
+    pub fn matches(&self, n: u64) -> bool {
+        let len = self.digits(n.into());
+        n > 0
+            && self
+                .allowed_times(len)
+                .any(|times| u128::from(n) % self.multiplier(len / times, times) == 0)
+    }
+
+    /// The matching numbers in `start..=end`, in ascending order.
+    pub fn numbers_in(&self, start: u64, end: u64) -> impl Iterator<Item = u64> {
+        let rule = *self;
+        (1..=rule.digits(end.into())).flat_map(move |len| {
+            rule.allowed_times(len)
+                .flat_map(|times| {
+                    let multiplier = rule.multiplier(len / times, times);
+                    rule.blocks(start, end, len / times, times)
+                        .map(move |block| (block * multiplier) as u64)
+                })
+                .sorted()
+                .dedup()
+        })
+    }
+
+    /// The sum of the matching numbers in `start..=end`, without enumerating
+    /// them.
+    ///
+    /// A number writing a block `b` times also writes a longer block `a` times
+    /// for every `a` dividing `b`, so only the allowed counts that no other
+    /// allowed count divides are needed. Numbers matching for several of them, like
+    /// 111111 which repeats blocks 2 and 3 times, are counted once by
+    /// inclusion–exclusion: they repeat a block as often as the least common
+    /// multiple of the counts.
+    pub fn sum_in(&self, start: u64, end: u64) -> u128 {
+        let total = (1..=self.digits(end.into()))
+            .flat_map(|len| {
+                let allowed = self.allowed_times(len).collect::<Vec<_>>();
+                allowed
+                    .iter()
+                    .copied()
+                    .filter(|&times| {
+                        !allowed
+                            .iter()
+                            .any(|&other| other < times && times.is_multiple_of(other))
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .powerset()
+                    .skip(1)
+                    .map(move |counts| {
+                        let times = counts.iter().copied().fold(1, lcm);
+                        let sum = self.sum_of_blocks(start, end, len / times, times) as i128;
+                        if counts.len() % 2 == 1 {
+                            sum
+                        } else {
+                            -sum
+                        }
+                    })
+            })
+            .sum::<i128>();
+
+        total as u128
+    }
+}
+
+fn lcm(a: u32, b: u32) -> u32 {
+    let gcd = |mut a: u32, mut b: u32| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    a / gcd(a, b) * b
+}
+
+#[derive(Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, ranges: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let twice = Repetition::exactly(2);
+        let result = ranges
+            .iter()
+            .map(|&(start, end)| twice.sum_in(start, end))
+            .sum::<u128>();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, ranges: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let at_least_twice = Repetition::at_least(2);
+        let result = ranges
+            .iter()
+            .map(|&(start, end)| at_least_twice.sum_in(start, end))
+            .sum::<u128>();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(1227775554, Day02.solve_part1(&read_example(2, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(4174379265_u64, Day02.solve_part2(&read_example(2, 1)?)?);
+        Ok(())
+    }
+
+    /// Whether the digits of `n` in `radix` are a block written `times`
+    /// times for an allowed `times`, by comparing the digits.
+    fn brute_force_matches(n: u64, radix: u64, times: RangeInclusive<usize>) -> bool {
+        let mut digits = Vec::new();
+        let mut rest = n;
+        while rest > 0 {
+            digits.push(rest % radix);
+            rest /= radix;
+        }
+
+        times.into_iter().any(|times| {
+            times > 0
+                && !digits.is_empty()
+                && digits.len() % times == 0
+                && digits
+                    .chunks(digits.len() / times)
+                    .all(|chunk| *chunk == digits[..digits.len() / times])
+        })
+    }
+
+    fn repetition() -> impl Strategy<Value = (Repetition, u64, RangeInclusive<usize>)> {
+        (2..=16_u32, 1..5_u32, 0..4_u32).prop_map(|(radix, min_times, extra)| {
+            let max_times = min_times + extra;
+            let rule = Repetition::between(min_times, max_times)
+                .in_radix(radix)
+                .expect("radix is valid");
+            (rule, radix.into(), min_times as usize..=max_times as usize)
+        })
+    }
+
+    #[test]
+    fn test_numbers_in() {
+        assert_eq!(
+            vec![1010, 1111, 1212],
+            Repetition::exactly(2)
+                .numbers_in(1000, 1250)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![99, 111, 222, 1010],
+            Repetition::at_least(2)
+                .numbers_in(99, 1010)
+                .filter(|n| *n < 300 || *n > 1000)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, Repetition::exactly(2).numbers_in(12, 21).count());
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Repetition::exactly(2).matches(222_222));
+        assert!(Repetition::exactly(3).matches(222_222));
+        assert!(!Repetition::exactly(4).matches(222_222));
+        assert!(Repetition::between(3, 4).matches(12_121_212));
+        assert!(!Repetition::between(3, 3).matches(12_121_212));
+        assert!(!Repetition::at_least(1).matches(0));
+    }
+
+    #[test]
+    fn test_other_radix() -> Result<(), AocError> {
+        let binary_twice = Repetition::exactly(2).in_radix(2)?;
+        assert!(binary_twice.matches(0b1010));
+        assert!(!binary_twice.matches(0b101));
+        assert_eq!(
+            vec![0b11, 0b1010, 0b1111],
+            binary_twice.numbers_in(0, 0b1111).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            0xabab,
+            Repetition::at_least(2).in_radix(16)?.sum_in(0xabaa, 0xabac)
+        );
+
+        assert!(Repetition::exactly(2).in_radix(1).is_err());
+        assert!(Repetition::exactly(2).in_radix(37).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_ranges() -> Result<(), AocError> {
+        let input = format!("1-{}", u64::MAX);
+        assert!(Day02.solve_part1(&input)? != Answer::Unsolved);
+        assert!(Day02.solve_part2(&input)? != Answer::Unsolved);
+        assert!(Repetition::at_least(2).in_radix(2)?.sum_in(0, u64::MAX) > 0);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_parts_match_brute_force(start in 0..200_000_u64, len in 0..5_000_u64) {
+            let end = start + len;
+            let input = format!("{start}-{end}");
+            let sum = |times: RangeInclusive<usize>| {
+                (start..=end)
+                    .filter(|&n| brute_force_matches(n, 10, times.clone()))
+                    .map(u128::from)
+                    .sum::<u128>()
+            };
+            prop_assert_eq!(sum(2..=2), Day02.solve_part1(&input)?);
+            prop_assert_eq!(sum(2..=20), Day02.solve_part2(&input)?);
+        }
+
+        #[test]
+        fn test_repetition_matches_brute_force(
+            (rule, radix, times) in repetition(),
+            start in 0..50_000_u64,
+            len in 0..2_000_u64,
+        ) {
+            let end = start + len;
+            let expected = (start..=end)
+                .filter(|&n| brute_force_matches(n, radix, times.clone()))
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(
+                &expected,
+                &(start..=end).filter(|&n| rule.matches(n)).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(&expected, &rule.numbers_in(start, end).collect::<Vec<_>>());
+            prop_assert_eq!(
+                expected.iter().copied().map(u128::from).sum::<u128>(),
+                rule.sum_in(start, end)
+            );
+        }
+    }
+}
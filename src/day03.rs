@@ -0,0 +1,113 @@
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u64>>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|n| n as u64)
+                        .ok_or(ValueError("Invalid character".to_string()))
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<u64>>, AocError>>()
+}
+
+fn get_max_value_and_first_index(slice: &[u64]) -> Option<(usize, u64)> {
+    slice
+        .iter()
+        .enumerate()
+        .fold(None, |acc, (index, &val)| match acc {
+            None => Some((index, val)),
+            Some((_, max_val)) => {
+                if val > max_val {
+                    Some((index, val))
+                } else {
+                    acc
+                }
+            }
+        })
+}
+
+#[derive(Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, lines: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result = lines
+            .iter()
+            .map(|line| {
+                if line.len() < 2 {
+                    return Err(ValueError("Bank has fewer than 2 batteries".to_string()));
+                }
+
+                let (max_10_pos, max_10) = get_max_value_and_first_index(&line[..line.len() - 1])
+                    .ok_or(ValueError("Invalid line".to_string()))?;
+
+                let (_, max_1) = get_max_value_and_first_index(&line[max_10_pos + 1..])
+                    .ok_or(ValueError("Invalid line".to_string()))?;
+
+                let max = 10 * max_10 + max_1;
+                Ok(max)
+            })
+            .sum::<Result<u64, AocError>>()?;
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, lines: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result = lines
+            .iter()
+            .map(|line| {
+                if line.len() < 12 {
+                    return Err(ValueError("Bank has fewer than 12 batteries".to_string()));
+                }
+
+                let result = (0..=11).rev().try_fold((0usize, 0u64), |acc, n| {
+                    let (current_pos, current_value) = acc;
+
+                    let (pos, value) =
+                        get_max_value_and_first_index(&line[current_pos..line.len() - n])
+                            .ok_or(ValueError("Invalid line".to_string()))?;
+
+                    Ok::<_, AocError>((pos + current_pos + 1, 10 * current_value + value))
+                });
+
+                result.map(|res| res.1)
+            })
+            .sum::<Result<u64, AocError>>()?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(357, Day03.solve_part1(&read_example(3, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(3121910778619_u64, Day03.solve_part2(&read_example(3, 1)?)?);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
-mod error;
-
-use crate::error::AocError;
+use crate::Answer;
+use crate::AocError;
+use crate::Solution;
 use glam::IVec2;
 use petgraph::Graph;
 use std::collections::{HashMap, HashSet};
@@ -26,7 +26,7 @@ const SURROUNDINGS: [IVec2; 8] = [
     BOTTOM_RIGHT,
 ];
 
-fn parse_input(input: &str) -> Graph<(), ()> {
+pub fn parse_input(input: &str) -> Graph<(), ()> {
     let map = input
         .lines()
         .enumerate()
@@ -52,94 +52,75 @@ fn parse_input(input: &str) -> Graph<(), ()> {
             .map(|&direction| coordinates + direction)
             .filter(|neighbor| map.contains(neighbor))
             .for_each(|neighbor| {
-                match (index_mapping.get(coordinates), index_mapping.get(&neighbor)) {
-                    (Some(index), Some(neighbor)) => {
-                        graph.add_edge(index.clone(), neighbor.clone(), ());
-                    }
-                    _ => {}
+                if let (Some(index), Some(neighbor)) =
+                    (index_mapping.get(coordinates), index_mapping.get(&neighbor))
+                {
+                    graph.add_edge(*index, *neighbor, ());
                 }
             })
     }
     graph
 }
 
-pub fn process_part1(_input: &str) -> Result<String, AocError> {
-    let graph = parse_input(_input);
-
-    let result = graph
-        .node_indices()
-        .filter(|ix| graph.neighbors(*ix).count() < 4)
-        .count();
+#[derive(Default)]
+pub struct Day04;
 
-    Ok(result.to_string())
-}
+impl Solution for Day04 {
+    const DAY: u8 = 4;
 
-pub fn process_part2(_input: &str) -> Result<String, AocError> {
-    let mut graph = parse_input(_input);
+    type Parsed<'a> = Graph<(), ()>;
 
-    let initial_number_of_rolls = graph.node_count();
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Ok(parse_input(input))
+    }
 
-    loop {
-        let free_rolls = graph
+    fn part1(&self, graph: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result = graph
             .node_indices()
             .filter(|ix| graph.neighbors(*ix).count() < 4)
-            .collect::<Vec<_>>();
+            .count();
 
-        if free_rolls.is_empty() {
-            break;
-        }
-
-        graph.retain_nodes(|_, ix| !free_rolls.contains(&ix));
+        Ok(result.into())
     }
 
-    let result = initial_number_of_rolls - graph.node_count();
+    fn part2(&self, graph: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut graph = graph.clone();
+
+        let initial_number_of_rolls = graph.node_count();
+
+        loop {
+            let free_rolls = graph
+                .node_indices()
+                .filter(|ix| graph.neighbors(*ix).count() < 4)
+                .collect::<Vec<_>>();
 
-    Ok(result.to_string())
+            if free_rolls.is_empty() {
+                break;
+            }
+
+            graph.retain_nodes(|_, ix| !free_rolls.contains(&ix));
+        }
+
+        let result = initial_number_of_rolls - graph.node_count();
+
+        Ok(result.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::read_example;
 
     #[test]
     fn test_process_part1() -> Result<(), AocError> {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-        assert_eq!("13", process_part1(input)?);
+        assert_eq!(13, Day04.solve_part1(&read_example(4, 1)?)?);
         Ok(())
     }
 
     #[test]
     fn test_process_part2() -> Result<(), AocError> {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-        assert_eq!("43", process_part2(input)?);
+        assert_eq!(43, Day04.solve_part2(&read_example(4, 1)?)?);
         Ok(())
     }
 }
-
-fn main() -> Result<(), AocError> {
-    let input = include_str!("../day-04.txt");
-    let result = process_part1(input);
-    println!("Part 1: {}", result?);
-
-    let result = process_part2(input);
-    println!("Part 2: {}", result?);
-    Ok(())
-}
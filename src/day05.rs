@@ -0,0 +1,216 @@
+extern crate core;
+
+use nom::Parser;
+use std::collections::HashSet;
+
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+use itertools::Itertools;
+use nom::bytes::tag;
+use nom::character::complete::u128;
+use nom::multi::{separated_list0, separated_list1};
+use nom::sequence::separated_pair;
+use std::ops::RangeInclusive;
+
+pub fn parse_input(input: &str) -> Result<(Vec<RangeInclusive<u128>>, Vec<u128>), AocError> {
+    let (ranges, ids) = parse_all(
+        input,
+        separated_pair(
+            separated_list1(
+                tag("\n"),
+                separated_pair(u128, tag("-"), u128).map(|(start, end)| start..=end),
+            ),
+            tag("\n\n"),
+            separated_list0(tag("\n"), u128),
+        ),
+    )?;
+
+    if let Some(range) = ranges.iter().find(|r| r.is_empty()) {
+        return Err(ValueError(format!(
+            "Range {}-{} ends before it starts",
+            range.start(),
+            range.end()
+        )));
+    }
+
+    Ok((ranges, ids))
+}
+
+fn remove_indices_from_vec<T>(v: &mut Vec<T>, ixs: &HashSet<usize>) {
+    ixs.iter().sorted().rev().for_each(|&i| {
+        v.remove(i);
+    })
+}
+
+#[derive(Default)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = (Vec<RangeInclusive<u128>>, Vec<u128>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (ranges, ids): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let number_of_fresh_items = ids
+            .iter()
+            .filter(|id| ranges.iter().any(|r| r.contains(id)))
+            .count();
+
+        Ok(number_of_fresh_items.into())
+    }
+
+    fn part2(&self, (ranges, _): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let disjoint_ranges =
+            ranges
+                .iter()
+                .cloned()
+                .fold(Vec::<RangeInclusive<u128>>::new(), |mut acc, r| {
+                    let includes_start = acc
+                        .iter()
+                        .cloned()
+                        .find_position(|acc_range| acc_range.contains(r.start()));
+
+                    let includes_end = acc
+                        .iter()
+                        .cloned()
+                        .find_position(|acc_range| acc_range.contains(r.end()));
+
+                    let mut ranges_to_remove = acc
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(ix, acc_range)| {
+                            if r.contains(acc_range.start())
+                                & r.contains(acc_range.end())
+                                & !(r == *acc_range)
+                            {
+                                Some(ix)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect::<HashSet<_>>();
+
+                    match (includes_start, includes_end) {
+                        (
+                            Some((inc_start_ix, inc_start_range)),
+                            Some((inc_end_ix, inc_end_range)),
+                        ) => {
+                            if inc_start_ix != inc_end_ix {
+                                ranges_to_remove.insert(inc_start_ix);
+                                ranges_to_remove.insert(inc_end_ix);
+                                acc.push(*inc_start_range.start()..=*inc_end_range.end())
+                            }
+                        }
+                        (Some((inc_start_ix, inc_start_range)), None) => {
+                            ranges_to_remove.insert(inc_start_ix);
+                            acc.push(*inc_start_range.start()..=*r.end())
+                        }
+                        (None, Some((inc_end_ix, inc_end_range))) => {
+                            ranges_to_remove.insert(inc_end_ix);
+                            acc.push(*r.start()..=*inc_end_range.end())
+                        }
+                        (None, None) => {
+                            acc.push(r);
+                        }
+                    };
+                    remove_indices_from_vec(&mut acc, &ranges_to_remove);
+                    acc
+                });
+
+        let total_length = disjoint_ranges
+            .iter()
+            .try_fold(0_u128, |total, r| {
+                (r.end() - r.start())
+                    .checked_add(1)
+                    .and_then(|length| total.checked_add(length))
+            })
+            .ok_or(ValueError("Result overflows".into()))?;
+
+        Ok(total_length.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(3, Day05.solve_part1(&read_example(5, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(14, Day05.solve_part2(&read_example(5, 1)?)?);
+        Ok(())
+    }
+
+    fn range() -> impl Strategy<Value = RangeInclusive<u128>> {
+        (0..100_u128, 0..20_u128).prop_map(|(start, len)| start..=start + len)
+    }
+
+    proptest! {
+        #[test]
+        fn test_part2_matches_set_of_ids(ranges in prop::collection::vec(range(), 1..20)) {
+            let ids = ranges.iter().cloned().flatten().collect::<HashSet<_>>();
+            prop_assert_eq!(ids.len(), Day05.part2(&(ranges, vec![]))?);
+        }
+    }
+}
+
+#[test]
+fn test_process_part2_test2() -> Result<(), AocError> {
+    let input = "3-5
+4-6
+8-10
+7-9
+16-20
+17-19
+30-31
+25-35
+
+1
+5
+8
+11
+17
+32
+";
+    assert_eq!(24, Day05.solve_part2(input)?);
+    Ok(())
+}
+
+#[test]
+fn test_process_part2_test3() -> Result<(), AocError> {
+    let input = "3-5
+4-6
+8-10
+7-9
+16-20
+17-19
+30-31
+25-35
+100-110
+120-130
+140-150
+105-145
+65-65
+65-65
+80-81
+81-82
+
+0
+";
+    assert_eq!(79, Day05.solve_part2(input)?);
+    Ok(())
+}
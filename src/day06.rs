@@ -0,0 +1,194 @@
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete::anychar;
+use nom::character::complete::u64;
+use nom::multi::{many0, many1, many_m_n};
+use nom::sequence::{delimited, pair, terminated};
+
+#[derive(Debug)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn apply(&self, a: u64, b: u64) -> Result<u64, AocError> {
+        match self {
+            Operation::Add => a.checked_add(b),
+            Operation::Multiply => a.checked_mul(b),
+        }
+        .ok_or(ValueError("Result overflows".into()))
+    }
+}
+
+fn checked_sum(values: impl IntoIterator<Item = u64>) -> Result<u64, AocError> {
+    values
+        .into_iter()
+        .try_fold(0_u64, u64::checked_add)
+        .ok_or(ValueError("Result overflows".into()))
+}
+
+/// Rows of numbers, in the order the operations are applied, and the operations.
+pub type Worksheet = (Vec<Vec<u64>>, Vec<Operation>);
+
+pub fn parse_input_part1(input: &str) -> Result<Worksheet, AocError> {
+    let num_lines = input.lines().count();
+    if num_lines == 0 {
+        return Err(ValueError("No data".into()));
+    }
+
+    let result = parse_all(
+        input,
+        pair(
+            many_m_n(
+                num_lines - 1,
+                num_lines - 1,
+                terminated(
+                    many1(delimited(many0(tag(" ")), u64, many0(tag(" ")))),
+                    tag("\n"),
+                ),
+            ),
+            many1(delimited(many0(tag(" ")), anychar, many0(tag(" ")))),
+        ),
+    )?;
+
+    let numbers = result.0;
+
+    let operations = result
+        .1
+        .iter()
+        .map(|op| match op {
+            '+' => Ok(Operation::Add),
+            '*' => Ok(Operation::Multiply),
+            _ => Err(ValueError("Invalid operation".into())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((numbers, operations))
+}
+
+pub fn parse_input_part2(input: &str) -> Result<Worksheet, AocError> {
+    let num_lines = input.lines().count();
+    if num_lines == 0 {
+        return Err(ValueError("No data".into()));
+    }
+    let number_lines = input
+        .lines()
+        .take(num_lines - 1)
+        .map(|line| line.to_string())
+        .collect::<Vec<String>>();
+    let operation_line = input.lines().rev().take(1).collect::<String>();
+
+    let number_line_length = number_lines
+        .first()
+        .ok_or(ValueError("No numbers".into()))?
+        .len();
+    let number_lines_transposed = (0..number_line_length)
+        .map(|i| {
+            number_lines
+                .iter()
+                .map(|line| {
+                    line.chars()
+                        .nth(i)
+                        .ok_or(ValueError("Different length lines".into()))
+                })
+                .collect::<Result<String, _>>()
+                .map(|s| s.trim().to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let numbers = number_lines_transposed
+        .iter()
+        .fold(vec![vec![]], |mut acc, line| {
+            match line.parse::<u64>() {
+                Ok(n) => acc.last_mut().unwrap().push(n),
+                Err(_) => acc.push(vec![]),
+            }
+            acc
+        });
+
+    let operations = operation_line
+        .trim()
+        .split(" ")
+        .filter(|s| !s.is_empty())
+        .map(|op| match op {
+            "+" => Ok(Operation::Add),
+            "*" => Ok(Operation::Multiply),
+            _ => Err(ValueError("Invalid operation".into())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((numbers, operations))
+}
+
+#[derive(Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = (Worksheet, Worksheet);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Ok((parse_input_part1(input)?, parse_input_part2(input)?))
+    }
+
+    fn part1(&self, ((numbers, operations), _): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut rows = numbers.iter();
+        let first_row = rows.next().ok_or(ValueError("Missing data".into()))?;
+        let totals = rows.try_fold(first_row.clone(), |acc, new| {
+            acc.into_iter()
+                .zip(new.iter())
+                .zip(operations.iter())
+                .map(|((acc_v, new_v), op)| op.apply(acc_v, *new_v))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        let result = checked_sum(totals)?;
+        Ok(result.into())
+    }
+
+    fn part2(&self, (_, (numbers, operations)): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result = numbers
+            .iter()
+            .zip(operations)
+            .map(|(num_set, op)| {
+                let (&first, rest) = num_set
+                    .split_first()
+                    .ok_or(ValueError("Missing data".into()))?;
+                rest.iter().try_fold(first, |acc, &new| op.apply(acc, new))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let sum = checked_sum(result)?;
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(4277556, Day06.solve_part1(&read_example(6, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(3263827, Day06.solve_part2(&read_example(6, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails_if_either_layout_fails() {
+        assert!(Day06::parse("12 3\n4 5\n* +").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use itertools::{repeat_n, Itertools};
+use std::collections::HashSet;
+
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub fn parse_input(input: &str) -> Result<(usize, usize, Vec<Vec<usize>>), AocError> {
+    let first_line = input.lines().next().ok_or(ValueError("No data".into()))?;
+
+    let width = first_line.chars().count();
+
+    let start_position = first_line
+        .chars()
+        .position(|c| c == 'S')
+        .ok_or(ValueError("No start position".into()))?;
+
+    let splitters = input
+        .lines()
+        .skip(1)
+        .map(|line| line.chars().positions(|c| c == '^').collect())
+        .collect();
+
+    Ok((width, start_position, splitters))
+}
+
+/// The positions of the two beams leaving a splitter at `pos`.
+fn split(pos: usize, width: usize) -> Result<[usize; 2], AocError> {
+    match pos.checked_sub(1) {
+        Some(left) if pos + 1 < width => Ok([left, pos + 1]),
+        _ => Err(ValueError(format!(
+            "Splitter at column {pos} sends a beam off the edge"
+        ))),
+    }
+}
+
+fn add_paths(a: u64, b: u64) -> Result<u64, AocError> {
+    a.checked_add(b)
+        .ok_or(ValueError("Result overflows".into()))
+}
+
+#[derive(Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed<'a> = (usize, usize, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(
+        &self,
+        &(width, start_position, ref splitters): &Self::Parsed<'_>,
+    ) -> Result<Answer, AocError> {
+        let result = splitters
+            .iter()
+            .try_fold((HashSet::from([start_position]), 0_u64), |b, x| {
+                let mut num_splits = b.1;
+                let new_beams =
+                    b.0.iter()
+                        .map(|&beam_pos| {
+                            if x.contains(&beam_pos) {
+                                num_splits += 1;
+                                Ok(split(beam_pos, width)?.to_vec())
+                            } else {
+                                Ok(vec![beam_pos])
+                            }
+                        })
+                        .flatten_ok()
+                        .collect::<Result<_, AocError>>()?;
+
+                Ok::<_, AocError>((new_beams, num_splits))
+            })?
+            .1;
+
+        Ok(result.into())
+    }
+
+    fn part2(
+        &self,
+        &(width, start_position, ref splitters): &Self::Parsed<'_>,
+    ) -> Result<Answer, AocError> {
+        let mut num_paths_to = repeat_n(0_u64, width).collect::<Vec<_>>();
+        num_paths_to[start_position] = 1;
+
+        let result = splitters
+            .iter()
+            .try_fold(num_paths_to, |acc, x| {
+                let mut new_num_paths_to = repeat_n(0_u64, width).collect::<Vec<_>>();
+                for (pos, &num_paths) in acc.iter().enumerate() {
+                    if num_paths > 0 {
+                        if x.contains(&pos) {
+                            for new_pos in split(pos, width)? {
+                                new_num_paths_to[new_pos] =
+                                    add_paths(new_num_paths_to[new_pos], num_paths)?;
+                            }
+                        } else {
+                            new_num_paths_to[pos] = add_paths(new_num_paths_to[pos], num_paths)?;
+                        }
+                    }
+                }
+                Ok::<_, AocError>(new_num_paths_to)
+            })?
+            .into_iter()
+            .try_fold(0, add_paths)?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(21, Day07.solve_part1(&read_example(7, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(40, Day07.solve_part2(&read_example(7, 1)?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use glam::I64Vec3;
+use itertools::Itertools;
+use petgraph::algo::tarjan_scc;
+use petgraph::unionfind::UnionFind;
+use petgraph::{Graph, Undirected};
+
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub fn parse_input(input: &str) -> Result<Vec<I64Vec3>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let coords: Result<[i64; 3], _> = line
+                .split(",")
+                .map(|n| {
+                    n.parse::<i64>()
+                        .map_err(|_| ValueError("Invalid number".into()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|res| {
+                    res.try_into()
+                        .map_err(|_| ValueError("Missing x, y or z coordinate".into()))
+                });
+            coords.map(I64Vec3::from)
+        })
+        .collect()
+}
+
+fn distance_squared(a: I64Vec3, b: I64Vec3) -> Result<i64, AocError> {
+    [(a.x, b.x), (a.y, b.y), (a.z, b.z)]
+        .into_iter()
+        .try_fold(0_i64, |acc, (a, b)| {
+            let d = a.checked_sub(b)?;
+            acc.checked_add(d.checked_mul(d)?)
+        })
+        .ok_or(ValueError("Junction boxes are too far apart".into()))
+}
+
+/// Pairs of junction boxes by index with the squared distance between them.
+pub type Pairs = Vec<(i64, (usize, usize))>;
+
+/// Every pair of junction boxes, closest first.
+fn closest_pairs(coordinates: &[I64Vec3]) -> Result<Pairs, AocError> {
+    Ok(coordinates
+        .iter()
+        .enumerate()
+        .tuple_combinations()
+        .map(|((ix1, &c1), (ix2, &c2))| Ok((distance_squared(c1, c2)?, (ix1, ix2))))
+        .collect::<Result<Vec<_>, AocError>>()?
+        .into_iter()
+        .sorted()
+        .collect())
+}
+
+/// Part 1 connects the `num_connections` closest pairs of junction boxes,
+/// 1000 for the real input and 10 for the example.
+pub struct Day08 {
+    pub num_connections: usize,
+}
+
+impl Default for Day08 {
+    fn default() -> Self {
+        Day08 {
+            num_connections: 1000,
+        }
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed<'a> = (Vec<I64Vec3>, Pairs);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        let coordinates = parse_input(input)?;
+        let pairs = closest_pairs(&coordinates)?;
+        Ok((coordinates, pairs))
+    }
+
+    fn part1(&self, (coordinates, pairs): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let connections = pairs
+            .iter()
+            .take(self.num_connections)
+            .map(|&(_, boxes)| boxes)
+            .collect::<Vec<_>>();
+
+        let graph: Graph<(), (), Undirected, usize> = Graph::from_edges(
+            connections
+                .into_iter()
+                .chain((0..coordinates.len()).map(|ix| (ix, ix))),
+        );
+
+        let components = tarjan_scc(&graph);
+        let component_sizes_multiplied = components
+            .iter()
+            .map(|component| component.len() as u128)
+            .sorted()
+            .rev()
+            .take(3)
+            .product::<u128>();
+
+        Ok(component_sizes_multiplied.into())
+    }
+
+    fn part2(&self, (coordinates, pairs): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut circuits = UnionFind::new(coordinates.len());
+        let mut num_circuits = coordinates.len();
+
+        let last_junction_boxes = pairs
+            .iter()
+            .map(|&(_, boxes)| boxes)
+            .find(|&(ix1, ix2)| {
+                if circuits.union(ix1, ix2) {
+                    num_circuits -= 1;
+                }
+                num_circuits == 1
+            })
+            .ok_or(ValueError("No coordinates".into()))?;
+
+        let result = coordinates[last_junction_boxes.0]
+            .x
+            .checked_mul(coordinates[last_junction_boxes.1].x)
+            .ok_or(ValueError("Result overflows".into()))?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        let day = Day08 {
+            num_connections: 10,
+        };
+        assert_eq!(40, day.solve_part1(&read_example(8, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(25272, Day08::default().solve_part2(&read_example(8, 1)?)?);
+        Ok(())
+    }
+}
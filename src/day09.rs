@@ -0,0 +1,110 @@
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::i64;
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use std::cmp::{max, min};
+
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub fn parse_input(input: &str) -> Result<Vec<(i64, i64)>, AocError> {
+    parse_all(
+        input,
+        separated_list0(tag("\n"), separated_pair(i64, tag(","), i64)),
+    )
+}
+
+/// The area of the rectangle with opposite corners `tile1` and `tile2`.
+fn area(tile1: &(i64, i64), tile2: &(i64, i64)) -> Result<i64, AocError> {
+    let side = |a: i64, b: i64| a.checked_sub(b)?.checked_abs()?.checked_add(1);
+    side(tile1.0, tile2.0)
+        .zip(side(tile1.1, tile2.1))
+        .and_then(|(width, height)| width.checked_mul(height))
+        .ok_or(ValueError("Tiles are too far apart".into()))
+}
+
+/// Whether `lo..=hi` contains a tile strictly between `start` and `end`.
+fn overlaps_inside(lo: i64, hi: i64, start: i64, end: i64) -> bool {
+    max(lo, start.saturating_add(1)) <= min(hi, end.saturating_sub(1))
+}
+
+#[derive(Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = Vec<(i64, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, red_tiles: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        red_tiles
+            .iter()
+            .tuple_combinations()
+            .map(|(tile1, tile2)| area(tile1, tile2))
+            .process_results(|areas| areas.max())?
+            .map(Answer::from)
+            .ok_or(ValueError("No data".into()))
+    }
+
+    fn part2(&self, red_tiles: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        // Edges as their corners, top left first. Only straight edges count.
+        let edges = red_tiles
+            .iter()
+            .circular_tuple_windows()
+            .filter(|(tile1, tile2)| tile1.0 == tile2.0 || tile1.1 == tile2.1)
+            .map(|(tile1, tile2)| {
+                (
+                    (min(tile1.0, tile2.0), min(tile1.1, tile2.1)),
+                    (max(tile1.0, tile2.0), max(tile1.1, tile2.1)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        red_tiles
+            .iter()
+            .tuple_combinations()
+            .map(|(tile1, tile2)| Ok((area(tile1, tile2)?, tile1, tile2)))
+            .collect::<Result<Vec<_>, AocError>>()?
+            .into_iter()
+            .sorted()
+            .rev()
+            .find(|(_, &tile1, &tile2)| {
+                let left = min(tile1.0, tile2.0);
+                let right = max(tile1.0, tile2.0);
+                let top = min(tile1.1, tile2.1);
+                let bottom = max(tile1.1, tile2.1);
+
+                !edges.iter().any(|&((x1, y1), (x2, y2))| {
+                    overlaps_inside(x1, x2, left, right) && overlaps_inside(y1, y2, top, bottom)
+                })
+            })
+            .map(|res| res.0.into())
+            .ok_or(ValueError("No data".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(50, Day09.solve_part1(&read_example(9, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(24, Day09.solve_part2(&read_example(9, 1)?)?);
+        Ok(())
+    }
+}
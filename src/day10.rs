@@ -0,0 +1,189 @@
+use good_lp::{
+    constraint, default_solver, variable, variables, Expression, Solution as _, SolverModel,
+};
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{one_of, u32, usize};
+use nom::multi::{many0, separated_list0, separated_list1};
+use nom::sequence::delimited;
+use nom::Parser;
+
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub type Machine = (Vec<bool>, Vec<Vec<usize>>, Vec<u32>);
+
+/// Part 1 tries every combination of button presses, so machines are limited
+/// to this many buttons.
+pub const MAX_BUTTONS: usize = 16;
+
+/// Part 1 keeps the lights of a machine in a `u64`.
+pub const MAX_LIGHTS: usize = 64;
+
+/// The lights toggled by turning on each of `lights` in turn.
+fn lights_mask(lights: impl IntoIterator<Item = usize>) -> u64 {
+    lights
+        .into_iter()
+        .fold(0, |mask, light| mask ^ (1 << light))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, AocError> {
+    let machines: Vec<Machine> = parse_all(
+        input,
+        separated_list1(
+            tag("\n"),
+            (
+                delimited(tag("["), many0(one_of(".#").map(|c| c == '#')), tag("]")),
+                delimited(
+                    tag(" "),
+                    separated_list0(
+                        tag(" "),
+                        delimited(tag("("), separated_list0(tag(","), usize), tag(")")),
+                    ),
+                    tag(" "),
+                ),
+                delimited(tag("{"), separated_list0(tag(","), u32), tag("}")),
+            ),
+        ),
+    )?;
+
+    for (indicators, buttons, joltage) in &machines {
+        if buttons.len() > MAX_BUTTONS {
+            return Err(ValueError(format!(
+                "Machine has {} buttons, more than the {MAX_BUTTONS} supported",
+                buttons.len()
+            )));
+        }
+        if indicators.len() > MAX_LIGHTS {
+            return Err(ValueError(format!(
+                "Machine has {} indicator lights, more than the {MAX_LIGHTS} supported",
+                indicators.len()
+            )));
+        }
+        if joltage.len() != indicators.len() {
+            return Err(ValueError(format!(
+                "Machine has {} indicator lights but {} joltage requirements",
+                indicators.len(),
+                joltage.len()
+            )));
+        }
+        if let Some(&light) = buttons.iter().flatten().find(|&&l| l >= indicators.len()) {
+            return Err(ValueError(format!(
+                "Button toggles light {light} of a machine with {} lights",
+                indicators.len()
+            )));
+        }
+    }
+
+    Ok(machines)
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, machines: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result: usize = machines
+            .iter()
+            .map(|(indicators, buttons, _)| {
+                let target = lights_mask(indicators.iter().positions(|&on| on));
+                let button_masks = buttons
+                    .iter()
+                    .map(|button| lights_mask(button.iter().copied()))
+                    .collect::<Vec<_>>();
+
+                (0_u32..1 << button_masks.len())
+                    .filter(|pressed| {
+                        button_masks
+                            .iter()
+                            .enumerate()
+                            .filter(|&(ix, _)| pressed & (1 << ix) != 0)
+                            .fold(0, |lights, (_, mask)| lights ^ mask)
+                            == target
+                    })
+                    .map(|pressed| pressed.count_ones() as usize)
+                    .min()
+                    .ok_or(ValueError("Target not reachable".into()))
+            })
+            .sum::<Result<usize, AocError>>()?;
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, machines: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let result: usize = machines
+            .iter()
+            .map(|(_, buttons, joltage)| {
+                let mut vars = variables!();
+                let x = (0..buttons.len())
+                    .map(|_| vars.add(variable().integer().min(0)))
+                    .collect::<Vec<_>>();
+                let objective: Expression = x.iter().sum();
+
+                let constraints = joltage
+                    .iter()
+                    .enumerate()
+                    .map(|(joltage_index, &joltage_val)| {
+                        constraint!(
+                            x.iter()
+                                .enumerate()
+                                .filter_map(|(button_index, var)| {
+                                    buttons
+                                        .get(button_index)
+                                        .unwrap()
+                                        .contains(&joltage_index)
+                                        .then_some(var)
+                                })
+                                .sum::<Expression>()
+                                == joltage_val
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let solution = constraints
+                    .into_iter()
+                    .fold(
+                        vars.minimise(objective).using(default_solver),
+                        |sol, constraint| sol.with(constraint),
+                    )
+                    .solve()
+                    .map_err(|_| ValueError("No solution found".into()))?;
+
+                Ok(x.into_iter()
+                    .map(|var| solution.value(var) as usize)
+                    .sum::<usize>())
+            })
+            .sum::<Result<usize, AocError>>()?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(7, Day10.solve_part1(&read_example(10, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(33, Day10.solve_part2(&read_example(10, 1)?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::alpha1;
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use petgraph::algo::is_cyclic_directed;
+use petgraph::graphmap::DiGraphMap;
+use petgraph::visit::Topo;
+use std::collections::HashMap;
+
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub fn parse_input(input: &str) -> Result<HashMap<&str, Vec<&str>>, AocError> {
+    let connections = parse_all(
+        input,
+        separated_list0(
+            tag("\n"),
+            separated_pair(alpha1, tag(": "), separated_list0(tag(" "), alpha1)),
+        ),
+    )?;
+
+    Ok(connections.into_iter().collect())
+}
+
+fn number_of_paths_between(
+    graph: &DiGraphMap<&str, ()>,
+    start: &str,
+    end: &str,
+) -> Result<usize, AocError> {
+    let mut path_counts = HashMap::new();
+    path_counts.insert(start, 1);
+
+    let mut topo = Topo::new(graph);
+
+    while let Some(node) = topo.next(graph) {
+        if let Some(&count) = path_counts.get(&node) {
+            for neighbor in graph.neighbors(node) {
+                let neighbor_count = path_counts.entry(neighbor).or_insert(0_usize);
+                *neighbor_count = neighbor_count
+                    .checked_add(count)
+                    .ok_or(ValueError("Result overflows".into()))?;
+            }
+        }
+    }
+
+    Ok(*path_counts.get(&end).unwrap_or(&0))
+}
+
+#[derive(Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, connections: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut graph: DiGraphMap<&str, ()> = DiGraphMap::new();
+
+        connections.iter().for_each(|(&machine, outputs)| {
+            outputs.iter().for_each(|&output| {
+                graph.add_edge(machine, output, ());
+            })
+        });
+
+        if let Some(device) = ["you", "out"]
+            .into_iter()
+            .find(|&device| !graph.contains_node(device))
+        {
+            return Err(ValueError(format!("No device named {device}")));
+        }
+
+        if is_cyclic_directed(&graph) {
+            return Err(ValueError("Cycle detected".into()));
+        }
+
+        let number_of_paths = number_of_paths_between(&graph, "you", "out")?;
+
+        Ok(number_of_paths.into())
+    }
+
+    fn part2(&self, connections: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut graph: DiGraphMap<&str, ()> = DiGraphMap::new();
+
+        connections.iter().for_each(|(&machine, outputs)| {
+            outputs.iter().for_each(|&output| {
+                graph.add_edge(machine, output, ());
+            })
+        });
+
+        if is_cyclic_directed(&graph) {
+            return Err(ValueError("Cycle detected".into()));
+        }
+
+        let srv_dac = number_of_paths_between(&graph, "svr", "dac")?;
+        let srv_fft = number_of_paths_between(&graph, "svr", "fft")?;
+        let dac_fft = number_of_paths_between(&graph, "dac", "fft")?;
+        let fft_dac = number_of_paths_between(&graph, "fft", "dac")?;
+        let dac_out = number_of_paths_between(&graph, "dac", "out")?;
+        let fft_out = number_of_paths_between(&graph, "fft", "out")?;
+
+        let number_of_paths = [[srv_dac, dac_fft, fft_out], [srv_fft, fft_dac, dac_out]]
+            .into_iter()
+            .try_fold(0_usize, |total, counts| {
+                let paths = counts
+                    .into_iter()
+                    .try_fold(1_usize, |product, count| product.checked_mul(count))?;
+                total.checked_add(paths)
+            })
+            .ok_or(ValueError("Result overflows".into()))?;
+
+        Ok(number_of_paths.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+    use petgraph::algo::all_simple_paths;
+    use proptest::prelude::*;
+    use std::hash::RandomState;
+    use std::iter;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(5, Day11.solve_part1(&read_example(11, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(2, Day11.solve_part2(&read_example(11, 2)?)?);
+        Ok(())
+    }
+
+    /// Graphs without cycles on the nodes `a` to `h`, with edges only going
+    /// from a node to a later one.
+    fn acyclic_graph() -> impl Strategy<Value = Vec<(&'static str, &'static str)>> {
+        const NODES: [&str; 8] = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        prop::collection::vec((0..NODES.len(), 1..NODES.len()), 0..30).prop_map(|edges| {
+            edges
+                .into_iter()
+                .filter(|&(from, offset)| from + offset < NODES.len())
+                .map(|(from, offset)| (NODES[from], NODES[from + offset]))
+                .collect()
+        })
+    }
+
+    /// Racks without cycles running from `svr` to `out`, with `dac`, `fft`
+    /// and a few other devices in between in any order.
+    fn acyclic_rack() -> impl Strategy<Value = Vec<(&'static str, &'static str)>> {
+        const DEVICES: [&str; 6] = ["dac", "fft", "a", "b", "c", "d"];
+        const NUM_NODES: usize = DEVICES.len() + 2;
+        (
+            Just(DEVICES.to_vec()).prop_shuffle(),
+            prop::collection::vec((0..NUM_NODES, 1..NUM_NODES), 0..40),
+        )
+            .prop_map(|(devices, edges)| {
+                let nodes = iter::once("svr")
+                    .chain(devices)
+                    .chain(iter::once("out"))
+                    .collect::<Vec<_>>();
+                edges
+                    .into_iter()
+                    .filter(|&(from, offset)| from + offset < NUM_NODES)
+                    .map(|(from, offset)| (nodes[from], nodes[from + offset]))
+                    .collect()
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_path_count_matches_all_simple_paths(
+            edges in acyclic_graph(),
+            start in "[a-h]",
+            end in "[a-h]",
+        ) {
+            let graph = DiGraphMap::<&str, ()>::from_edges(edges);
+            prop_assume!(graph.contains_node(&start) && graph.contains_node(&end));
+            prop_assume!(start != end);
+
+            let simple_paths = all_simple_paths::<Vec<_>, _, RandomState>(
+                &graph, &start, &end, 0, None,
+            )
+            .count();
+            prop_assert_eq!(simple_paths, number_of_paths_between(&graph, &start, &end)?);
+        }
+
+        #[test]
+        fn test_part2_matches_all_simple_paths(edges in acyclic_rack()) {
+            let graph = DiGraphMap::<&str, ()>::from_edges(&edges);
+            prop_assume!(graph.contains_node("svr") && graph.contains_node("out"));
+
+            let simple_paths = all_simple_paths::<Vec<_>, _, RandomState>(
+                &graph, "svr", "out", 0, None,
+            )
+            .filter(|path| path.contains(&"dac") && path.contains(&"fft"))
+            .count();
+
+            let connections = edges.into_iter().fold(
+                HashMap::<&str, Vec<&str>>::new(),
+                |mut connections, (from, to)| {
+                    connections.entry(from).or_default().push(to);
+                    connections
+                },
+            );
+            prop_assert_eq!(simple_paths, Day11.part2(&connections)?);
+        }
+    }
+}
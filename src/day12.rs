@@ -0,0 +1,96 @@
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{one_of, u64, usize};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{preceded, separated_pair};
+use nom::Parser;
+
+use crate::parse::parse_all;
+use crate::Answer;
+use crate::AocError;
+use crate::AocError::ValueError;
+use crate::Solution;
+
+pub type Present = Vec<Vec<bool>>;
+pub type Region = ((usize, usize), Vec<usize>);
+
+pub fn parse_input(input: &str) -> Result<(Vec<Present>, Vec<Region>), AocError> {
+    parse_all(
+        input,
+        separated_pair(
+            separated_list1(
+                tag("\n\n"),
+                preceded(
+                    (u64, tag(":\n")),
+                    separated_list1(tag("\n"), many1(one_of("#.").map(|c| c == '#'))),
+                ),
+            ),
+            tag("\n\n"),
+            separated_list1(
+                tag("\n"),
+                separated_pair(
+                    separated_pair(usize, tag("x"), usize),
+                    tag(": "),
+                    separated_list1(tag(" "), usize),
+                ),
+            ),
+        ),
+    )
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed<'a> = (Vec<Present>, Vec<Region>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (presents, regions): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let present_sizes = presents
+            .iter()
+            .map(|present| present.iter().flatten().filter(|&&v| v).count())
+            .collect::<Vec<usize>>();
+
+        let result = regions
+            .iter()
+            .map(|((width, height), present_counts)| {
+                let area = width.checked_mul(*height);
+                let presents_area = present_counts
+                    .iter()
+                    .zip(present_sizes.iter())
+                    .try_fold(0_usize, |acc, (count, size)| {
+                        acc.checked_add(count.checked_mul(*size)?)
+                    });
+
+                area.zip(presents_area)
+                    .map(|(area, presents_area)| area >= presents_area)
+                    .ok_or(ValueError("Region is too large".into()))
+            })
+            .process_results(|fits| fits.filter(|&fits| fits).count())?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_example;
+
+    #[test]
+    fn test_process_part1() -> Result<(), AocError> {
+        assert_eq!(2, Day12.solve_part1(&read_example(12, 1)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_part2() -> Result<(), AocError> {
+        assert_eq!(Answer::Unsolved, Day12.solve_part2(&read_example(12, 1)?)?);
+        Ok(())
+    }
+}
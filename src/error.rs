@@ -3,10 +3,77 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum AocError {
-    #[error(transparent)]
+    #[error("IoError: {0}")]
     IoError(#[from] std::io::Error),
     #[error(transparent)]
     ParseIntError(#[from] ParseIntError),
+    #[error(
+        "ParseError: expected {expected} at line {line}, column {column}\n{}",
+        render_snippet(*line, *column, snippet)
+    )]
+    ParseError {
+        line: usize,
+        column: usize,
+        expected: String,
+        snippet: String,
+    },
     #[error("ValueError: {0}")]
     ValueError(String),
 }
+
+impl AocError {
+    /// Builds a [`AocError::ParseError`] pointing at the start of `remaining`,
+    /// which must be a suffix of `input`.
+    pub fn parse_error(input: &str, remaining: &str, expected: impl Into<String>) -> Self {
+        let offset = input.len() - remaining.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |ix| offset + ix);
+
+        AocError::ParseError {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+}
+
+/// Renders the offending line with a caret under the given column.
+fn render_snippet(line: usize, column: usize, snippet: &str) -> String {
+    let gutter = line.to_string().len();
+    format!(
+        "{:gutter$} |\n{line} | {snippet}\n{:gutter$} | {:>column$}",
+        "", "", "^"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "3-5\n10-14\n16x20\n";
+        let error = AocError::parse_error(input, &input[12..], "tag");
+
+        let AocError::ParseError {
+            line,
+            column,
+            ref snippet,
+            ..
+        } = error
+        else {
+            panic!("expected a ParseError, got {error:?}");
+        };
+        assert_eq!((3, 3), (line, column));
+        assert_eq!("16x20", snippet);
+        assert_eq!(
+            "ParseError: expected tag at line 3, column 3\n  |\n3 | 16x20\n  |   ^",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::AocError;
+use crate::AocError::IoError;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory in which `day-NN.txt` inputs are looked up when no explicit path is given.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Conventional location of a day's puzzle input.
+pub fn default_input_path(day: u8) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day-{day:02}.txt"))
+}
+
+/// Directory in which the puzzle examples are kept as `day-NN-<k>.txt`.
+pub const EXAMPLE_DIR: &str = "examples";
+
+/// Location of the `index`-th example block of a day's puzzle text,
+/// counting from 1.
+pub fn example_path(day: u8, index: usize) -> PathBuf {
+    Path::new(EXAMPLE_DIR).join(format!("day-{day:02}-{index}.txt"))
+}
+
+/// Reads the `index`-th example of `day`, passed through [`normalize`].
+pub fn read_example(day: u8, index: usize) -> Result<String, AocError> {
+    Ok(normalize(&read_input_file(&example_path(day, index))?))
+}
+
+/// Reads the input of `day` from `path`, from stdin if `path` is `-`,
+/// or from [`default_input_path`] if no path is given. The input is passed
+/// through [`normalize`].
+pub fn read_input(day: u8, path: Option<&Path>) -> Result<String, AocError> {
+    let input = match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+        Some(path) => read_input_file(path)?,
+        None => read_input_file(&default_input_path(day))?,
+    };
+    Ok(normalize(&input))
+}
+
+pub fn read_input_file(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|e| {
+        IoError(io::Error::new(
+            e.kind(),
+            format!("cannot read input file `{}`: {e}", path.display()),
+        ))
+    })
+}
+
+/// Strips a byte order mark, converts CRLF line endings to LF and removes
+/// trailing blank lines including the final newline. Whitespace within and
+/// at the end of the remaining lines is kept, as column-aligned puzzles such
+/// as day 6 depend on it.
+pub fn normalize(input: &str) -> String {
+    let mut normalized = input
+        .strip_prefix('\u{feff}')
+        .unwrap_or(input)
+        .replace("\r\n", "\n");
+
+    let content_end = normalized.trim_end().len();
+    let last_line_end = normalized[content_end..]
+        .find('\n')
+        .map_or(normalized.len(), |ix| content_end + ix);
+    normalized.truncate(last_line_end);
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_line_endings_and_bom() {
+        assert_eq!("3-5\n\n1\n5", normalize("\u{feff}3-5\r\n\r\n1\r\n5\r\n"));
+    }
+
+    #[test]
+    fn test_normalize_keeps_significant_whitespace() {
+        assert_eq!(
+            "123 328\n 45 64 \n*   +  ",
+            normalize("123 328\n 45 64 \n*   +  \n\n  \n")
+        );
+    }
+
+    #[test]
+    fn test_missing_input_file() {
+        let error = read_input_file(Path::new("inputs/day-99.txt")).unwrap_err();
+        assert!(matches!(error, IoError(_)));
+        assert!(error.to_string().contains("`inputs/day-99.txt`"));
+    }
+
+    #[test]
+    fn test_read_example() -> Result<(), AocError> {
+        assert_eq!(
+            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ",
+            read_example(6, 1)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+mod answer;
+mod error;
+pub mod input;
+pub mod parse;
+pub mod registry;
+mod solution;
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use solution::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
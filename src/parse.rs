@@ -0,0 +1,62 @@
+use crate::AocError;
+use nom::Parser;
+
+/// Runs a nom `parser` over `input` and converts failures, as well as any
+/// unparsed content other than trailing whitespace, into
+/// [`AocError::ParseError`] with the position of the problem. Inputs are
+/// always complete, so streaming parsers such as `nom::bytes::tag` do not
+/// ask for more input at the end.
+pub fn parse_all<'a, O, P>(input: &'a str, mut parser: P) -> Result<O, AocError>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    match parser.parse_complete(input) {
+        Ok((remaining, output)) if remaining.trim().is_empty() => Ok(output),
+        Ok((remaining, _)) => Err(AocError::parse_error(input, remaining, "end of input")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(AocError::parse_error(
+            input,
+            e.input,
+            e.code.description().to_lowercase(),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(AocError::parse_error(input, "", "more input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::u64;
+    use nom::multi::separated_list1;
+
+    #[test]
+    fn test_parse_all_reports_unparsed_content() {
+        let error = parse_all("1\n2\r\n3\n", separated_list1(tag("\n"), u64)).unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::ParseError {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_all_allows_trailing_whitespace() -> Result<(), AocError> {
+        assert_eq!(
+            vec![1, 2, 3],
+            parse_all("1\n2\n3\n", separated_list1(tag("\n"), u64))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_all_runs_streaming_parsers_to_the_end() -> Result<(), AocError> {
+        assert_eq!(
+            vec![1, 2],
+            parse_all("1\n2", separated_list1(nom::bytes::tag("\n"), u64))?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::*;
+
+/// Parses a day's input into something that can solve both of its parts.
+pub type ParseFn = for<'a> fn(&'a str) -> Result<Box<dyn ParsedInput + 'a>, AocError>;
+
+/// A day's input parsed by its [`Solution`], so that both parts share the
+/// work of parsing.
+pub trait ParsedInput {
+    fn part1(&self) -> Result<Answer, AocError>;
+    fn part2(&self) -> Result<Answer, AocError>;
+
+    fn part(&self, part: u8) -> Result<Answer, AocError> {
+        match part {
+            1 => self.part1(),
+            _ => self.part2(),
+        }
+    }
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: S,
+    parsed: S::Parsed<'a>,
+}
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    fn part1(&self) -> Result<Answer, AocError> {
+        self.solution.part1(&self.parsed)
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        self.solution.part2(&self.parsed)
+    }
+}
+
+/// Entry points of a single day's puzzle. `parse` is timed on its own, and
+/// the parts are then solved on its result.
+pub struct Solver {
+    pub day: u8,
+    pub parse: ParseFn,
+}
+
+impl Solver {
+    const fn new<S: Solution + Default + 'static>() -> Self {
+        Solver {
+            day: S::DAY,
+            parse: parse::<S>,
+        }
+    }
+}
+
+fn parse<S: Solution + Default + 'static>(
+    input: &str,
+) -> Result<Box<dyn ParsedInput + '_>, AocError> {
+    Ok(Box::new(Parsed {
+        solution: S::default(),
+        parsed: S::parse(input)?,
+    }))
+}
+
+/// All solved days, in order. Adding a day is one entry here.
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day01::Day01>(),
+    Solver::new::<day02::Day02>(),
+    Solver::new::<day03::Day03>(),
+    Solver::new::<day04::Day04>(),
+    Solver::new::<day05::Day05>(),
+    Solver::new::<day06::Day06>(),
+    Solver::new::<day07::Day07>(),
+    Solver::new::<day08::Day08>(),
+    Solver::new::<day09::Day09>(),
+    Solver::new::<day10::Day10>(),
+    Solver::new::<day11::Day11>(),
+    Solver::new::<day12::Day12>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|solver| solver.day == day)
+}
@@ -0,0 +1,33 @@
+use crate::Answer;
+use crate::AocError;
+
+/// A day's puzzle. The input is parsed once into [`Solution::Parsed`], which
+/// may borrow from the input, and both parts work on the parsed form.
+///
+/// Parameters that the puzzle text fixes for the real input but changes for
+/// the examples, such as the number of connections on day 8, are fields of
+/// the implementing type; its `Default` holds the values for the real input.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError>;
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Result<Answer, AocError>;
+
+    /// Days without a second part keep this default.
+    fn part2(&self, _parsed: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+
+    /// Parses `input` and solves part 1 on it.
+    fn solve_part1(&self, input: &str) -> Result<Answer, AocError> {
+        self.part1(&Self::parse(input)?)
+    }
+
+    /// Parses `input` and solves part 2 on it.
+    fn solve_part2(&self, input: &str) -> Result<Answer, AocError> {
+        self.part2(&Self::parse(input)?)
+    }
+}
@@ -0,0 +1,145 @@
+//! Every solver must answer malformed or degenerate input with an error
+//! rather than a panic.
+
+use advent_of_code_2025::input::{example_path, read_example};
+use advent_of_code_2025::registry::{Solver, SOLVERS};
+use std::iter;
+use std::panic::{self, AssertUnwindSafe};
+
+const DEGENERATE_INPUTS: &[&str] = &[
+    "",
+    "\n",
+    "\n\n",
+    " ",
+    "x",
+    "0",
+    "-1",
+    "^",
+    "S",
+    "S\n^",
+    ".S\n.^",
+    "L2147483647\nL2147483647",
+    "R2147483647\nR2147483647",
+    ":",
+    "0:\n",
+    "1x1: 1",
+    "5-3",
+    "5-3\n\n4",
+    "0-340282366920938463463374607431768211455\n\n",
+    "0,0,0",
+    "1,1\n1,1",
+    "99999999999 99999999999\n99999999999 99999999999\n*           *",
+    "99999999999\n99999999999\n*",
+    "4294967295,1,1\n1,1,1\n-4294967295,0,0",
+    "2147483647,2147483647\n-2147483647,-2147483647",
+    "[] {}",
+    "[.] (1) {1}",
+    "99999999999999999999999999999999999999999",
+    "\u{1f384}",
+];
+
+/// Inputs too large to write out whose answers overflow: a day 7 manifold
+/// where every beam splits on every row, and a day 11 rack with two devices
+/// per layer between `svr` and `dac`.
+fn overflowing_inputs() -> Vec<String> {
+    const LAYERS: usize = 70;
+
+    let width = 2 * LAYERS + 1;
+    let manifold = (0..=LAYERS)
+        .map(|row| {
+            (0..width)
+                .map(|col| match row {
+                    0 if col == LAYERS => 'S',
+                    0 => '.',
+                    _ if col > 0 && col < width - 1 && col % 2 == (LAYERS + row - 1) % 2 => '^',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let layer = |ix: usize| {
+        ["x", "y"].map(|prefix| {
+            format!(
+                "{prefix}{}{}",
+                (b'a' + (ix / 26) as u8) as char,
+                (b'a' + (ix % 26) as u8) as char
+            )
+        })
+    };
+    let rack = iter::once(("svr".to_string(), layer(0)))
+        .chain((1..LAYERS).flat_map(|ix| layer(ix - 1).map(|device| (device, layer(ix)))))
+        .chain(layer(LAYERS - 1).map(|device| (device, ["dac".to_string(), "fft".to_string()])))
+        .map(|(device, outputs)| format!("{device}: {}", outputs.join(" ")))
+        .chain(["dac: fft".to_string(), "fft: out".to_string()])
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    vec![manifold, rack]
+}
+
+/// The examples of `day`, each cut off after every character and with every
+/// line left out in turn.
+fn damaged_examples(day: u8) -> Vec<String> {
+    (1..)
+        .take_while(|&index| example_path(day, index).exists())
+        .map(|index| read_example(day, index).expect("examples are checked in"))
+        .flat_map(|example| {
+            let prefixes = example
+                .char_indices()
+                .map(|(ix, _)| example[..ix].to_string())
+                .collect::<Vec<_>>();
+            let lines = example.lines().collect::<Vec<_>>();
+            let without_a_line = (0..lines.len()).map(move |skipped| {
+                lines
+                    .iter()
+                    .enumerate()
+                    .filter(|&(ix, _)| ix != skipped)
+                    .map(|(_, line)| *line)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            });
+            prefixes
+                .into_iter()
+                .chain(without_a_line.collect::<Vec<_>>())
+        })
+        .collect()
+}
+
+fn panics(solver: &Solver, input: &str) -> bool {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        if let Ok(parsed) = (solver.parse)(input) {
+            let _ = parsed.part1();
+            let _ = parsed.part2();
+        }
+    }))
+    .is_err()
+}
+
+#[test]
+fn test_solvers_do_not_panic() {
+    panic::set_hook(Box::new(|_| {}));
+
+    let panicking = SOLVERS
+        .iter()
+        .flat_map(|solver| {
+            DEGENERATE_INPUTS
+                .iter()
+                .map(|input| input.to_string())
+                .chain(overflowing_inputs())
+                .chain(damaged_examples(solver.day))
+                .filter(|input| panics(solver, input))
+                .map(|input| format!("day {:02}: {input:?}", solver.day))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let _ = panic::take_hook();
+    assert!(
+        panicking.is_empty(),
+        "{} input(s) panic:\n{}",
+        panicking.len(),
+        panicking.join("\n")
+    );
+}